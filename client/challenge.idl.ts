@@ -82,6 +82,11 @@ export type Challenge = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'challengeTreasury';
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: 'systemProgram';
           isMut: false;
@@ -113,11 +118,21 @@ export type Challenge = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'challengeMetadata';
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: 'challengeRegistry';
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'challengeSeries';
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: 'systemProgram';
           isMut: false;
@@ -133,6 +148,72 @@ export type Challenge = {
         },
       ];
     },
+    {
+      name: 'updateChallenge';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'UpdateChallengeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'updateChallengeMetadata';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challengeMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'UpdateChallengeMetadataParams';
+          };
+        },
+      ];
+    },
     {
       name: 'cancelChallenge';
       accounts: [
@@ -242,6 +323,11 @@ export type Challenge = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'challengeTreasury';
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: 'systemProgram';
           isMut: false;
@@ -295,1264 +381,7412 @@ export type Challenge = {
         },
       ];
     },
-  ];
-  accounts: [
-    {
-      name: 'challengePlatformRegistry';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'owner';
-            type: 'publicKey';
-          },
-          {
-            name: 'wasInitialized';
-            type: 'bool';
-          },
-          {
-            name: 'bump';
-            type: 'u8';
-          },
-          {
-            name: 'allowedAdministrators';
-            type: {
-              vec: 'publicKey';
-            };
-          },
-          {
-            name: 'allowedMintAccounts';
-            type: {
-              vec: {
-                defined: 'MintInfo';
-              };
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'challenge';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'id';
-            type: 'string';
-          },
-          {
-            name: 'bump';
-            type: 'u8';
-          },
-          {
-            name: 'owner';
-            type: 'publicKey';
-          },
-          {
-            name: 'minDeposit';
-            type: 'u64';
-          },
-          {
-            name: 'players';
-            type: {
-              vec: {
-                defined: 'PlayerInfo';
-              };
-            };
-          },
-          {
-            name: 'prizePool';
-            type: 'u64';
-          },
-          {
-            name: 'donatePool';
-            type: 'u64';
-          },
-          {
-            name: 'rewardTokenMintAccount';
-            type: 'publicKey';
-          },
-          {
-            name: 'status';
-            type: {
-              defined: 'ChallengeStatus';
-            };
-          },
-        ];
-      };
-    },
-  ];
-  types: [
     {
-      name: 'CancelChallengeParams';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'id';
-            type: 'string';
-          },
-        ];
-      };
+      name: 'expireChallenge';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ExpireChallengeParams';
+          };
+        },
+      ];
     },
     {
-      name: 'CreateChallengeParams';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'id';
-            type: 'string';
-          },
-          {
-            name: 'minDeposit';
-            type: 'u64';
-          },
-          {
-            name: 'rewardTokenMintAccount';
-            type: 'publicKey';
-          },
-        ];
-      };
+      name: 'openDispute';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'dispute';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'OpenDisputeParams';
+          };
+        },
+      ];
     },
     {
-      name: 'InitializeChallengePlatformParams';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'allowedMintAccounts';
+      name: 'resolveDispute';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'dispute';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ResolveDisputeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'createChallengeSeries';
+      accounts: [
+        {
+          name: 'seriesOwner';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeSeries';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'CreateChallengeSeriesParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'spawnSeriesRound';
+      accounts: [
+        {
+          name: 'seriesOwner';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeSeries';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'SpawnSeriesRoundParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'recordCheckIn';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'RecordCheckInParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'settleMilestoneChallenge';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'SettleMilestoneChallengeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'submitProof';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'submission';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'SubmitProofParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'reviewProof';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'submission';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ReviewProofParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'applyNoWinnerPolicy';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeTreasury';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rolloverTarget';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ApplyNoWinnerPolicyParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'applyNoWinnerRollover';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'sourceChallenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'targetChallenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ApplyNoWinnerRolloverParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'amendWinnerList';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'AmendWinnerListParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'createChallengeTemplate';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeTemplate';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ChallengeTemplateParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'updateChallengeTemplate';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeTemplate';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ChallengeTemplateParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'createChallengeFromTemplate';
+      accounts: [
+        {
+          name: 'challengeOwner';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeTemplate';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challengeSeries';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'CreateChallengeFromTemplateParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'updateChallengeFromTemplate';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeTemplate';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'UpdateChallengeFromTemplateParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'transitionChallengeStatus';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'TransitionChallengeStatusParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'forceCancelChallenge';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'ForceCancelChallengeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'closeChallenge';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challengeOwner';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeTreasury';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'CloseChallengeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'withdrawFees';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'mintAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'signerTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeTokenVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'challengeTreasury';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'WithdrawFeesParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setMintProtocolFee';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'SetMintProtocolFeeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'closeDispute';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'disputer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'dispute';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'CloseDisputeParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'closeSubmission';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challenge';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'player';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'submission';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'params';
+          type: {
+            defined: 'CloseSubmissionParams';
+          };
+        },
+      ];
+    },
+    {
+      name: 'createTreasury';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'challengeRegistry';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mintAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'challengeTreasury';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+  ];
+  accounts: [
+    {
+      name: 'challengePlatformRegistry';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'wasInitialized';
+            type: 'bool';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'allowedAdministrators';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'allowedMintAccounts';
+            type: {
+              vec: {
+                defined: 'MintInfo';
+              };
+            };
+          },
+          {
+            name: 'expiryGracePeriod';
+            type: 'i64';
+          },
+          {
+            name: 'disputeWindow';
+            type: 'i64';
+          },
+          {
+            name: 'correctionWindow';
+            type: 'i64';
+          },
+          {
+            name: 'protocolFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'maxCreatorFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'referralBps';
+            type: 'u16';
+          },
+          {
+            name: 'referralFeeSource';
+            type: {
+              defined: 'ReferralFeeSource';
+            };
+          },
+          {
+            name: 'mintProtocolFees';
+            type: {
+              vec: {
+                defined: 'MintProtocolFee';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'challenge';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'players';
+            type: {
+              vec: {
+                defined: 'PlayerInfo';
+              };
+            };
+          },
+          {
+            name: 'prizePool';
+            type: 'u64';
+          },
+          {
+            name: 'donatePool';
+            type: 'u64';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'status';
+            type: {
+              defined: 'ChallengeStatus';
+            };
+          },
+          {
+            name: 'joinDeadline';
+            type: 'i64';
+          },
+          {
+            name: 'endTime';
+            type: 'i64';
+          },
+          {
+            name: 'disputeDeadline';
+            type: 'i64';
+          },
+          {
+            name: 'resultSubmittedAt';
+            type: 'i64';
+          },
+          {
+            name: 'maxPlayers';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'maxDepositPerPlayer';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'maxPrizePool';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'startTime';
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'earlyExitPenaltyBps';
+            type: 'u16';
+          },
+          {
+            name: 'series';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'round';
+            type: 'u64';
+          },
+          {
+            name: 'milestones';
+            type: {
+              vec: 'i64';
+            };
+          },
+          {
+            name: 'judge';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'noWinnerPolicy';
+            type: {
+              defined: 'NoWinnerPolicy';
+            };
+          },
+          {
+            name: 'noWinnerRolloverTarget';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'payoutModel';
+            type: {
+              defined: 'PayoutModel';
+            };
+          },
+          {
+            name: 'feeBps';
+            type: 'u16';
+          },
+          {
+            name: 'collectedFee';
+            type: 'u64';
+          },
+          {
+            name: 'isFeeCollected';
+            type: 'bool';
+          },
+          {
+            name: 'template';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'cancelReasonCode';
+            type: 'u16';
+          },
+          {
+            name: 'cancelReasonUri';
+            type: 'string';
+          },
+          {
+            name: 'donors';
+            type: {
+              vec: {
+                defined: 'DonorInfo';
+              };
+            };
+          },
+          {
+            name: 'payoutTable';
+            type: {
+              vec: 'u16';
+            };
+          },
+          {
+            name: 'payoutRemainderPolicy';
+            type: {
+              defined: 'PayoutRemainderPolicy';
+            };
+          },
+          {
+            name: 'payoutRemainder';
+            type: 'u64';
+          },
+          {
+            name: 'isPayoutRemainderSettled';
+            type: 'bool';
+          },
+          {
+            name: 'creatorFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'creatorFee';
+            type: 'u64';
+          },
+          {
+            name: 'isCreatorFeeClaimed';
+            type: 'bool';
+          },
+          {
+            name: 'referrers';
+            type: {
+              vec: {
+                defined: 'ReferrerInfo';
+              };
+            };
+          },
+          {
+            name: 'isDisputeResolved';
+            type: 'bool';
+          },
+          {
+            name: 'rolloverPool';
+            type: 'u64';
+          },
+          {
+            name: 'totalSubmissions';
+            type: 'u64';
+          },
+          {
+            name: 'isDisputeOpened';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'challengeDispute';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'challenge';
+            type: 'publicKey';
+          },
+          {
+            name: 'disputer';
+            type: 'publicKey';
+          },
+          {
+            name: 'reasonUri';
+            type: 'string';
+          },
+          {
+            name: 'openedAt';
+            type: 'i64';
+          },
+          {
+            name: 'status';
+            type: {
+              defined: 'DisputeStatus';
+            };
+          },
+          {
+            name: 'resolver';
+            type: 'publicKey';
+          },
+          {
+            name: 'resolvedAt';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'challengeMetadata';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'challenge';
+            type: 'publicKey';
+          },
+          {
+            name: 'title';
+            type: 'string';
+          },
+          {
+            name: 'descriptionUri';
+            type: 'string';
+          },
+          {
+            name: 'category';
+            type: 'string';
+          },
+          {
+            name: 'rulesHash';
+            type: {
+              array: [
+                'u8',
+                32,
+              ];
+            };
+          },
+          {
+            name: 'coverImageUri';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'challengeSeries';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'joinDuration';
+            type: 'i64';
+          },
+          {
+            name: 'duration';
+            type: 'i64';
+          },
+          {
+            name: 'round';
+            type: 'u64';
+          },
+          {
+            name: 'rolloverEnabled';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'challengeSubmission';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'challenge';
+            type: 'publicKey';
+          },
+          {
+            name: 'player';
+            type: 'publicKey';
+          },
+          {
+            name: 'evidenceUri';
+            type: 'string';
+          },
+          {
+            name: 'contentHash';
+            type: {
+              array: [
+                'u8',
+                32,
+              ];
+            };
+          },
+          {
+            name: 'submittedAt';
+            type: 'i64';
+          },
+          {
+            name: 'status';
+            type: {
+              defined: 'SubmissionStatus';
+            };
+          },
+          {
+            name: 'reviewer';
+            type: 'publicKey';
+          },
+          {
+            name: 'reviewedAt';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'challengeTreasury';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'mintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'balance';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'challengeTemplate';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'isEnabled';
+            type: 'bool';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'maxDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'minDuration';
+            type: 'i64';
+          },
+          {
+            name: 'maxDuration';
+            type: 'i64';
+          },
+          {
+            name: 'payoutModel';
+            type: {
+              defined: 'PayoutModel';
+            };
+          },
+          {
+            name: 'feeBps';
+            type: 'u16';
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: 'AmendWinnerListParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'winnerList';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'ApplyNoWinnerPolicyParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ApplyNoWinnerRolloverParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'sourceChallengeId';
+            type: 'string';
+          },
+          {
+            name: 'targetChallengeId';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CancelChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CloseChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CloseDisputeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CloseSubmissionParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'player';
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CreateChallengeFromTemplateParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'templateId';
+            type: 'string';
+          },
+          {
+            name: 'challenge';
+            type: {
+              defined: 'CreateChallengeParams';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'CreateChallengeSeriesParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'joinDuration';
+            type: 'i64';
+          },
+          {
+            name: 'duration';
+            type: 'i64';
+          },
+          {
+            name: 'rolloverEnabled';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ChallengeTemplateParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'isEnabled';
+            type: 'bool';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'maxDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'minDuration';
+            type: 'i64';
+          },
+          {
+            name: 'maxDuration';
+            type: 'i64';
+          },
+          {
+            name: 'payoutModel';
+            type: {
+              defined: 'PayoutModel';
+            };
+          },
+          {
+            name: 'feeBps';
+            type: 'u16';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ChallengeMetadataParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'title';
+            type: 'string';
+          },
+          {
+            name: 'descriptionUri';
+            type: 'string';
+          },
+          {
+            name: 'category';
+            type: 'string';
+          },
+          {
+            name: 'rulesHash';
+            type: {
+              array: [
+                'u8',
+                32,
+              ];
+            };
+          },
+          {
+            name: 'coverImageUri';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CreateChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'joinDeadline';
+            type: 'i64';
+          },
+          {
+            name: 'endTime';
+            type: 'i64';
+          },
+          {
+            name: 'maxPlayers';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'maxDepositPerPlayer';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'maxPrizePool';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'startTime';
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'earlyExitPenaltyBps';
+            type: 'u16';
+          },
+          {
+            name: 'milestones';
+            type: {
+              vec: 'i64';
+            };
+          },
+          {
+            name: 'judge';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'noWinnerPolicy';
+            type: {
+              defined: 'NoWinnerPolicy';
+            };
+          },
+          {
+            name: 'noWinnerRolloverTarget';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'payoutModel';
+            type: {
+              defined: 'PayoutModel';
+            };
+          },
+          {
+            name: 'payoutTable';
+            type: {
+              vec: 'u16';
+            };
+          },
+          {
+            name: 'payoutRemainderPolicy';
+            type: {
+              defined: 'PayoutRemainderPolicy';
+            };
+          },
+          {
+            name: 'creatorFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'metadata';
+            type: {
+              defined: 'ChallengeMetadataParams';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'ExpireChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ForceCancelChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'reasonCode';
+            type: 'u16';
+          },
+          {
+            name: 'reasonUri';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'InitializeChallengePlatformParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'allowedMintAccounts';
+            type: {
+              vec: {
+                defined: 'MintInfo';
+              };
+            };
+          },
+          {
+            name: 'allowedAdministrators';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'expiryGracePeriod';
+            type: 'i64';
+          },
+          {
+            name: 'disputeWindow';
+            type: 'i64';
+          },
+          {
+            name: 'correctionWindow';
+            type: 'i64';
+          },
+          {
+            name: 'protocolFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'maxCreatorFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'referralBps';
+            type: 'u16';
+          },
+          {
+            name: 'referralFeeSource';
+            type: {
+              defined: 'ReferralFeeSource';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'OpenDisputeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'reasonUri';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'RecordCheckInParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'player';
+            type: 'publicKey';
+          },
+          {
+            name: 'milestoneIndex';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ResolveDisputeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'actionType';
+            type: {
+              defined: 'ResolveDisputeActionType';
+            };
+          },
+          {
+            name: 'winnerList';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'ReviewProofParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'player';
+            type: 'publicKey';
+          },
+          {
+            name: 'actionType';
+            type: {
+              defined: 'ReviewProofActionType';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'SetMintProtocolFeeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'protocolFeeBps';
+            type: {
+              option: 'u16';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'SettleMilestoneChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SpawnSeriesRoundParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'seriesId';
+            type: 'string';
+          },
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'metadata';
+            type: {
+              defined: 'ChallengeMetadataParams';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'SubmitProofParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'evidenceUri';
+            type: 'string';
+          },
+          {
+            name: 'contentHash';
+            type: {
+              array: [
+                'u8',
+                32,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'SubmitWinnersParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'winnerList';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'TransferAssetsFromVaultParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeTokenVaultBump';
+            type: 'u8';
+          },
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'actionType';
+            type: {
+              defined: 'TransferAssetsFromVaultActionType';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'TransferAssetsToVaultParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeTokenVaultBump';
+            type: 'u8';
+          },
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+          {
+            name: 'actionType';
+            type: {
+              defined: 'TransferAssetsToVaultActionType';
+            };
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'referrer';
+            type: {
+              option: 'publicKey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'TransitionChallengeStatusParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeId';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'UpdateChallengeFromTemplateParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'templateId';
+            type: 'string';
+          },
+          {
+            name: 'challenge';
+            type: {
+              defined: 'UpdateChallengeParams';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'UpdateChallengeMetadataParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'metadata';
+            type: {
+              defined: 'ChallengeMetadataParams';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'UpdateChallengePlatformParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'allowedMintAccounts';
+            type: {
+              vec: {
+                defined: 'MintInfo';
+              };
+            };
+          },
+          {
+            name: 'allowedAdministrators';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'expiryGracePeriod';
+            type: 'i64';
+          },
+          {
+            name: 'disputeWindow';
+            type: 'i64';
+          },
+          {
+            name: 'correctionWindow';
+            type: 'i64';
+          },
+          {
+            name: 'protocolFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'maxCreatorFeeBps';
+            type: 'u16';
+          },
+          {
+            name: 'referralBps';
+            type: 'u16';
+          },
+          {
+            name: 'referralFeeSource';
+            type: {
+              defined: 'ReferralFeeSource';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'UpdateChallengeParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'string';
+          },
+          {
+            name: 'minDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'rewardTokenMintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'joinDeadline';
+            type: 'i64';
+          },
+          {
+            name: 'endTime';
+            type: 'i64';
+          },
+          {
+            name: 'maxPlayers';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'maxDepositPerPlayer';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'maxPrizePool';
+            type: {
+              option: 'u64';
+            };
+          },
+          {
+            name: 'startTime';
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'earlyExitPenaltyBps';
+            type: 'u16';
+          },
+          {
+            name: 'milestones';
+            type: {
+              vec: 'i64';
+            };
+          },
+          {
+            name: 'judge';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'noWinnerPolicy';
+            type: {
+              defined: 'NoWinnerPolicy';
+            };
+          },
+          {
+            name: 'noWinnerRolloverTarget';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'payoutModel';
+            type: {
+              defined: 'PayoutModel';
+            };
+          },
+          {
+            name: 'payoutTable';
+            type: {
+              vec: 'u16';
+            };
+          },
+          {
+            name: 'payoutRemainderPolicy';
+            type: {
+              defined: 'PayoutRemainderPolicy';
+            };
+          },
+          {
+            name: 'creatorFeeBps';
+            type: 'u16';
+          },
+        ];
+      };
+    },
+    {
+      name: 'WithdrawFeesParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'challengeTokenVaultBump';
+            type: 'u8';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'MintInfo';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'isEnabled';
+            type: 'bool';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'mintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenAccount';
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'MintProtocolFee';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mintAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'protocolFeeBps';
+            type: 'u16';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PlayerInfo';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'publicKey';
+            type: 'publicKey';
+          },
+          {
+            name: 'totalDeposit';
+            type: 'u64';
+          },
+          {
+            name: 'isWinner';
+            type: 'bool';
+          },
+          {
+            name: 'isWinnerClaimedReward';
+            type: 'bool';
+          },
+          {
+            name: 'isPlayerWithdrawn';
+            type: 'bool';
+          },
+          {
+            name: 'checkedInMilestones';
+            type: 'u32';
+          },
+          {
+            name: 'isEliminated';
+            type: 'bool';
+          },
+          {
+            name: 'rank';
+            type: 'u8';
+          },
+          {
+            name: 'referrerIndex';
+            type: {
+              option: 'u8';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'DonorInfo';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'publicKey';
+            type: 'publicKey';
+          },
+          {
+            name: 'totalDonation';
+            type: 'u64';
+          },
+          {
+            name: 'isDonationRefunded';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ReferrerInfo';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'publicKey';
+            type: 'publicKey';
+          },
+          {
+            name: 'referralReward';
+            type: 'u64';
+          },
+          {
+            name: 'isReferralRewardClaimed';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ResolveDisputeActionType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'UpholdWinnerList';
+          },
+          {
+            name: 'ReplaceWinnerList';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ReviewProofActionType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'AcceptProof';
+          },
+          {
+            name: 'RejectProof';
+          },
+        ];
+      };
+    },
+    {
+      name: 'TransferAssetsFromVaultActionType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Claiming';
+          },
+          {
+            name: 'Withdrawing';
+          },
+          {
+            name: 'AdminWithdrawingDonatePool';
+          },
+          {
+            name: 'LeavingChallenge';
+          },
+          {
+            name: 'RefundingDonation';
+          },
+          {
+            name: 'ClaimingCreatorFee';
+          },
+          {
+            name: 'ClaimingReferralReward';
+          },
+        ];
+      };
+    },
+    {
+      name: 'TransferAssetsToVaultActionType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'JoinChallenge';
+          },
+          {
+            name: 'Donate';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ChallengeStatus';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Open';
+          },
+          {
+            name: 'Finalized';
+          },
+          {
+            name: 'Canceled';
+          },
+          {
+            name: 'Claimed';
+          },
+          {
+            name: 'Withdrawn';
+          },
+          {
+            name: 'PendingResult';
+          },
+          {
+            name: 'Disputed';
+          },
+          {
+            name: 'Upcoming';
+          },
+          {
+            name: 'Running';
+          },
+        ];
+      };
+    },
+    {
+      name: 'NoWinnerPolicy';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'RefundPlayers';
+          },
+          {
+            name: 'SendToTreasury';
+          },
+          {
+            name: 'RollOver';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PayoutModel';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'EqualSplit';
+          },
+          {
+            name: 'Ranked';
+          },
+          {
+            name: 'StakeProportional';
+          },
+          {
+            name: 'StakeBack';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PayoutRemainderPolicy';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'LastClaimer';
+          },
+          {
+            name: 'TopRankedWinner';
+          },
+          {
+            name: 'Treasury';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ReferralFeeSource';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'ProtocolFee';
+          },
+          {
+            name: 'CreatorFee';
+          },
+        ];
+      };
+    },
+    {
+      name: 'DisputeStatus';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Opened';
+          },
+          {
+            name: 'Upheld';
+          },
+          {
+            name: 'Replaced';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SubmissionStatus';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Pending';
+          },
+          {
+            name: 'Accepted';
+          },
+          {
+            name: 'Rejected';
+          },
+        ];
+      };
+    },
+  ];
+  events: [
+    {
+      name: 'ChallengeRegistryUpdated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'allowedAdministrators';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+        {
+          name: 'allowedMintAccounts';
+          type: {
+            vec: {
+              defined: 'MintInfo';
+            };
+          };
+          index: false;
+        },
+        {
+          name: 'expiryGracePeriod';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'disputeWindow';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'correctionWindow';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'protocolFeeBps';
+          type: 'u16';
+          index: false;
+        },
+        {
+          name: 'maxCreatorFeeBps';
+          type: 'u16';
+          index: false;
+        },
+        {
+          name: 'referralBps';
+          type: 'u16';
+          index: false;
+        },
+        {
+          name: 'referralFeeSource';
+          type: {
+            defined: 'ReferralFeeSource';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'VaultCreated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'mintAccount';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'associatedAccount';
+          type: 'publicKey';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeCreated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeUpdated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeMetadataUpdated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'metadataKey';
+          type: 'publicKey';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'WinnerListSubmitted';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'winnerList';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+        {
+          name: 'disputeDeadline';
+          type: 'i64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'DisputeOpened';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'disputeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'reasonUri';
+          type: 'string';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'DisputeResolved';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'disputeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'status';
+          type: {
+            defined: 'DisputeStatus';
+          };
+          index: false;
+        },
+        {
+          name: 'winnerList';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeFinalized';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'status';
+          type: {
+            defined: 'ChallengeStatus';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeCanceled';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'status';
+          type: {
+            defined: 'ChallengeStatus';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeExpired';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'status';
+          type: {
+            defined: 'ChallengeStatus';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RewardReceived';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeId';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'rewardMintToken';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'actionType';
+          type: {
+            defined: 'TransferAssetsToVaultActionType';
+          };
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RewardClaimed';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeId';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'rewardMintToken';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'actionType';
+          type: {
+            defined: 'TransferAssetsFromVaultActionType';
+          };
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PlayerLeft';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeId';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'refundAmount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'penaltyAmount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeSeriesCreated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'seriesKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'SeriesRoundSpawned';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'seriesKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'round';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PrizePoolRolledOver';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'sourceChallengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'targetChallengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'CheckInRecorded';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'player';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'milestoneIndex';
+          type: 'u8';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PlayerEliminated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'player';
+          type: 'publicKey';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'ProofSubmitted';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'submissionKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'evidenceUri';
+          type: 'string';
+          index: false;
+        },
+        {
+          name: 'contentHash';
+          type: {
+            array: [
+              'u8',
+              32,
+            ];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ProofReviewed';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'submissionKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'player';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'status';
+          type: {
+            defined: 'SubmissionStatus';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'NoWinnerPolicyApplied';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'policy';
+          type: {
+            defined: 'NoWinnerPolicy';
+          };
+          index: true;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'WinnerListAmended';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'previousWinnerList';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+        {
+          name: 'winnerList';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeTemplateUpdated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'templateKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'isEnabled';
+          type: 'bool';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeStatusChanged';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'from';
+          type: {
+            defined: 'ChallengeStatus';
+          };
+          index: false;
+        },
+        {
+          name: 'to';
+          type: {
+            defined: 'ChallengeStatus';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ChallengeClosed';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'id';
+          type: 'string';
+          index: true;
+        },
+        {
+          name: 'owner';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'status';
+          type: {
+            defined: 'ChallengeStatus';
+          };
+          index: false;
+        },
+        {
+          name: 'rewardTokenMintAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'totalPlayers';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'totalWinners';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'totalDeposit';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'prizePool';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'collectedFee';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'reclaimedLamports';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'MintProtocolFeeUpdated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'mintAccount';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'protocolFeeBps';
+          type: {
+            option: 'u16';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'DisputeClosed';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'disputeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'disputer';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SubmissionClosed';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'submissionKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'player';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'FeesWithdrawn';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'treasuryKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'mintAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'TreasuryCreated';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'treasuryKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'mintAccount';
+          type: 'publicKey';
+          index: true;
+        },
+      ];
+    },
+    {
+      name: 'ReferralRecorded';
+      fields: [
+        {
+          name: 'actor';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeKey';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'referrer';
+          type: 'publicKey';
+          index: true;
+        },
+        {
+          name: 'challengeId';
+          type: 'string';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'AlreadyInitialized';
+      msg: 'The program was already initialized';
+    },
+    {
+      code: 6001;
+      name: 'MintAccountExisted';
+      msg: 'The mint account was existed';
+    },
+    {
+      code: 6002;
+      name: 'OnlyAdministrator';
+      msg: 'Only Platform Admin';
+    },
+    {
+      code: 6003;
+      name: 'OnlyOwner';
+      msg: 'Only Owner';
+    },
+    {
+      code: 6004;
+      name: 'OrderExpired';
+      msg: 'Order expired';
+    },
+    {
+      code: 6005;
+      name: 'InvalidOffer';
+      msg: 'Invalid Offer';
+    },
+    {
+      code: 6006;
+      name: 'InvalidValue';
+      msg: 'Invalid value';
+    },
+    {
+      code: 6007;
+      name: 'UnAllowedMintToken';
+      msg: 'Invalid value';
+    },
+    {
+      code: 6008;
+      name: 'ChallengeCannotBeCanceled';
+      msg: 'Challenge cannot be canceled';
+    },
+    {
+      code: 6009;
+      name: 'WithdrawalIsNotAvailable';
+      msg: 'Withdrawal is not available for the challenge';
+    },
+    {
+      code: 6010;
+      name: 'ClaimIsNotAvailable';
+      msg: 'Claim is not available for the challenge';
+    },
+    {
+      code: 6011;
+      name: 'TransferTokenFromVaultIsNotAvailable';
+      msg: 'Transfer token from vault is not available for the challenge';
+    },
+    {
+      code: 6012;
+      name: 'DepositIsNotAvailable';
+      msg: 'Deposit is not available for the challenge';
+    },
+    {
+      code: 6013;
+      name: 'OnlyParticipant';
+      msg: 'Only participants can execute this operation';
+    },
+    {
+      code: 6014;
+      name: 'AlreadyParticipated';
+      msg: 'The participant already participated in the challenge.';
+    },
+    {
+      code: 6015;
+      name: 'MinDepositIsNotReached';
+      msg: 'Min deposit amount is not reached';
+    },
+    {
+      code: 6016;
+      name: 'JoiningClosed';
+      msg: 'Joining is closed for the challenge';
+    },
+    {
+      code: 6017;
+      name: 'ChallengeNotEndedYet';
+      msg: 'The challenge has not ended yet';
+    },
+    {
+      code: 6018;
+      name: 'ChallengeCannotBeExpired';
+      msg: 'Challenge cannot be expired';
+    },
+    {
+      code: 6019;
+      name: 'DisputeIsNotAvailable';
+      msg: 'Dispute is not available for the challenge';
+    },
+    {
+      code: 6020;
+      name: 'DisputeAlreadyResolved';
+      msg: 'The dispute was already resolved';
+    },
+    {
+      code: 6021;
+      name: 'MaxPlayersReached';
+      msg: 'Max players of the challenge is reached';
+    },
+    {
+      code: 6022;
+      name: 'MaxDepositPerPlayerExceeded';
+      msg: 'Max deposit per player is exceeded';
+    },
+    {
+      code: 6023;
+      name: 'MaxPrizePoolExceeded';
+      msg: 'Max prize pool of the challenge is exceeded';
+    },
+    {
+      code: 6024;
+      name: 'ChallengeCannotBeUpdated';
+      msg: 'Challenge cannot be updated';
+    },
+    {
+      code: 6025;
+      name: 'LeavingIsNotAvailable';
+      msg: 'Leaving is not available for the challenge';
+    },
+    {
+      code: 6026;
+      name: 'RolloverIsNotAvailable';
+      msg: 'Rollover is not available for the challenge';
+    },
+    {
+      code: 6027;
+      name: 'CheckInIsNotAvailable';
+      msg: 'Check-in is not available for the milestone';
+    },
+    {
+      code: 6028;
+      name: 'PlayerEliminated';
+      msg: 'The player was eliminated from the challenge';
+    },
+    {
+      code: 6029;
+      name: 'SubmissionIsNotAvailable';
+      msg: 'Proof submission is not available for the challenge';
+    },
+    {
+      code: 6030;
+      name: 'SubmissionAlreadyReviewed';
+      msg: 'The submission was already reviewed';
+    },
+    {
+      code: 6031;
+      name: 'NoWinnerPolicyIsNotAvailable';
+      msg: 'No-winner policy is not available for the challenge';
+    },
+    {
+      code: 6032;
+      name: 'WinnerListCannotBeAmended';
+      msg: 'The winner list cannot be amended';
+    },
+    {
+      code: 6033;
+      name: 'TemplateIsNotAvailable';
+      msg: 'The challenge template is not available';
+    },
+    {
+      code: 6034;
+      name: 'TemplateConstraintViolated';
+      msg: 'The challenge settings violate the template';
+    },
+    {
+      code: 6035;
+      name: 'StatusTransitionIsNotAvailable';
+      msg: 'The challenge status is already up to date';
+    },
+    {
+      code: 6036;
+      name: 'MaxDonorsReached';
+      msg: 'Max donors of the challenge is reached';
+    },
+    {
+      code: 6037;
+      name: 'DonationRefundIsNotAvailable';
+      msg: 'Donation refund is not available';
+    },
+    {
+      code: 6038;
+      name: 'ChallengeCannotBeClosed';
+      msg: 'The challenge still has outstanding liabilities';
+    },
+    {
+      code: 6039;
+      name: 'StatusTransitionIsNotAllowed';
+      msg: 'The challenge status transition is not allowed';
+    },
+    {
+      code: 6040;
+      name: 'MaxReferrersReached';
+      msg: 'Max referrers of the challenge is reached';
+    },
+  ];
+};
+
+export const IDL: Challenge = {
+  version: '0.1.0',
+  name: 'challenge',
+  instructions: [
+    {
+      name: 'initialize',
+      accounts: [
+        {
+          name: 'owner',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'InitializeChallengePlatformParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'updateChallengeRegistry',
+      accounts: [
+        {
+          name: 'owner',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'UpdateChallengePlatformParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createTokenVault',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mintAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTokenVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTreasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createChallenge',
+      accounts: [
+        {
+          name: 'challengeOwner',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeSeries',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CreateChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'updateChallenge',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'UpdateChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'updateChallengeMetadata',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'UpdateChallengeMetadataParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'cancelChallenge',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CancelChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'transferAssetsToVault',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'mintAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'signerTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTokenVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'TransferAssetsToVaultParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'transferAssetsFromVault',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'mintAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'signerTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTokenVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTreasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'TransferAssetsFromVaultParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'submitWinnerList',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'SubmitWinnersParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'expireChallenge',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ExpireChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'openDispute',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'dispute',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'OpenDisputeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'resolveDispute',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'dispute',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ResolveDisputeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createChallengeSeries',
+      accounts: [
+        {
+          name: 'seriesOwner',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeSeries',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CreateChallengeSeriesParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'spawnSeriesRound',
+      accounts: [
+        {
+          name: 'seriesOwner',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeSeries',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'SpawnSeriesRoundParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'recordCheckIn',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'RecordCheckInParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'settleMilestoneChallenge',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'SettleMilestoneChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'submitProof',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'submission',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'SubmitProofParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'reviewProof',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'submission',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ReviewProofParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'applyNoWinnerPolicy',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTreasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rolloverTarget',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ApplyNoWinnerPolicyParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'applyNoWinnerRollover',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'sourceChallenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'targetChallenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ApplyNoWinnerRolloverParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'amendWinnerList',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'AmendWinnerListParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createChallengeTemplate',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeTemplate',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ChallengeTemplateParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'updateChallengeTemplate',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeTemplate',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ChallengeTemplateParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createChallengeFromTemplate',
+      accounts: [
+        {
+          name: 'challengeOwner',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeTemplate',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeSeries',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CreateChallengeFromTemplateParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'updateChallengeFromTemplate',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeTemplate',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'UpdateChallengeFromTemplateParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'transitionChallengeStatus',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'TransitionChallengeStatusParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'forceCancelChallenge',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'ForceCancelChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'closeChallenge',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeOwner',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTreasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CloseChallengeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'withdrawFees',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'mintAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'signerTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTokenVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTreasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'WithdrawFeesParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setMintProtocolFee',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'SetMintProtocolFeeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'closeDispute',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'disputer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'dispute',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CloseDisputeParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'closeSubmission',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challenge',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'player',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'submission',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'params',
+          type: {
+            defined: 'CloseSubmissionParams',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createTreasury',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'challengeRegistry',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mintAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'challengeTreasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: 'challengePlatformRegistry',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'wasInitialized',
+            type: 'bool',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'allowedAdministrators',
+            type: {
+              vec: 'publicKey',
+            },
+          },
+          {
+            name: 'allowedMintAccounts',
+            type: {
+              vec: {
+                defined: 'MintInfo',
+              },
+            },
+          },
+          {
+            name: 'expiryGracePeriod',
+            type: 'i64',
+          },
+          {
+            name: 'disputeWindow',
+            type: 'i64',
+          },
+          {
+            name: 'correctionWindow',
+            type: 'i64',
+          },
+          {
+            name: 'protocolFeeBps',
+            type: 'u16',
+          },
+          {
+            name: 'maxCreatorFeeBps',
+            type: 'u16',
+          },
+          {
+            name: 'referralBps',
+            type: 'u16',
+          },
+          {
+            name: 'referralFeeSource',
+            type: {
+              defined: 'ReferralFeeSource',
+            },
+          },
+          {
+            name: 'mintProtocolFees',
+            type: {
+              vec: {
+                defined: 'MintProtocolFee',
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'challenge',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'minDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'players',
+            type: {
+              vec: {
+                defined: 'PlayerInfo',
+              },
+            },
+          },
+          {
+            name: 'prizePool',
+            type: 'u64',
+          },
+          {
+            name: 'donatePool',
+            type: 'u64',
+          },
+          {
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'status',
+            type: {
+              defined: 'ChallengeStatus',
+            },
+          },
+          {
+            name: 'joinDeadline',
+            type: 'i64',
+          },
+          {
+            name: 'endTime',
+            type: 'i64',
+          },
+          {
+            name: 'disputeDeadline',
+            type: 'i64',
+          },
+          {
+            name: 'resultSubmittedAt',
+            type: 'i64',
+          },
+          {
+            name: 'maxPlayers',
+            type: {
+              option: 'u64',
+            },
+          },
+          {
+            name: 'maxDepositPerPlayer',
+            type: {
+              option: 'u64',
+            },
+          },
+          {
+            name: 'maxPrizePool',
+            type: {
+              option: 'u64',
+            },
+          },
+          {
+            name: 'startTime',
+            type: {
+              option: 'i64',
+            },
+          },
+          {
+            name: 'earlyExitPenaltyBps',
+            type: 'u16',
+          },
+          {
+            name: 'series',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'round',
+            type: 'u64',
+          },
+          {
+            name: 'milestones',
+            type: {
+              vec: 'i64',
+            },
+          },
+          {
+            name: 'judge',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'noWinnerPolicy',
+            type: {
+              defined: 'NoWinnerPolicy',
+            },
+          },
+          {
+            name: 'noWinnerRolloverTarget',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'payoutModel',
+            type: {
+              defined: 'PayoutModel',
+            },
+          },
+          {
+            name: 'feeBps',
+            type: 'u16',
+          },
+          {
+            name: 'collectedFee',
+            type: 'u64',
+          },
+          {
+            name: 'isFeeCollected',
+            type: 'bool',
+          },
+          {
+            name: 'template',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'cancelReasonCode',
+            type: 'u16',
+          },
+          {
+            name: 'cancelReasonUri',
+            type: 'string',
+          },
+          {
+            name: 'donors',
+            type: {
+              vec: {
+                defined: 'DonorInfo',
+              },
+            },
+          },
+          {
+            name: 'payoutTable',
+            type: {
+              vec: 'u16',
+            },
+          },
+          {
+            name: 'payoutRemainderPolicy',
+            type: {
+              defined: 'PayoutRemainderPolicy',
+            },
+          },
+          {
+            name: 'payoutRemainder',
+            type: 'u64',
+          },
+          {
+            name: 'isPayoutRemainderSettled',
+            type: 'bool',
+          },
+          {
+            name: 'creatorFeeBps',
+            type: 'u16',
+          },
+          {
+            name: 'creatorFee',
+            type: 'u64',
+          },
+          {
+            name: 'isCreatorFeeClaimed',
+            type: 'bool',
+          },
+          {
+            name: 'referrers',
+            type: {
+              vec: {
+                defined: 'ReferrerInfo',
+              },
+            },
+          },
+          {
+            name: 'isDisputeResolved',
+            type: 'bool',
+          },
+          {
+            name: 'rolloverPool',
+            type: 'u64',
+          },
+          {
+            name: 'totalSubmissions',
+            type: 'u64',
+          },
+          {
+            name: 'isDisputeOpened',
+            type: 'bool',
+          },
+        ],
+      },
+    },
+    {
+      name: 'challengeDispute',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'challenge',
+            type: 'publicKey',
+          },
+          {
+            name: 'disputer',
+            type: 'publicKey',
+          },
+          {
+            name: 'reasonUri',
+            type: 'string',
+          },
+          {
+            name: 'openedAt',
+            type: 'i64',
+          },
+          {
+            name: 'status',
+            type: {
+              defined: 'DisputeStatus',
+            },
+          },
+          {
+            name: 'resolver',
+            type: 'publicKey',
+          },
+          {
+            name: 'resolvedAt',
+            type: 'i64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'challengeMetadata',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'challenge',
+            type: 'publicKey',
+          },
+          {
+            name: 'title',
+            type: 'string',
+          },
+          {
+            name: 'descriptionUri',
+            type: 'string',
+          },
+          {
+            name: 'category',
+            type: 'string',
+          },
+          {
+            name: 'rulesHash',
+            type: {
+              array: [
+                'u8',
+                32,
+              ],
+            },
+          },
+          {
+            name: 'coverImageUri',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'challengeSeries',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'minDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'joinDuration',
+            type: 'i64',
+          },
+          {
+            name: 'duration',
+            type: 'i64',
+          },
+          {
+            name: 'round',
+            type: 'u64',
+          },
+          {
+            name: 'rolloverEnabled',
+            type: 'bool',
+          },
+        ],
+      },
+    },
+    {
+      name: 'challengeSubmission',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'challenge',
+            type: 'publicKey',
+          },
+          {
+            name: 'player',
+            type: 'publicKey',
+          },
+          {
+            name: 'evidenceUri',
+            type: 'string',
+          },
+          {
+            name: 'contentHash',
+            type: {
+              array: [
+                'u8',
+                32,
+              ],
+            },
+          },
+          {
+            name: 'submittedAt',
+            type: 'i64',
+          },
+          {
+            name: 'status',
+            type: {
+              defined: 'SubmissionStatus',
+            },
+          },
+          {
+            name: 'reviewer',
+            type: 'publicKey',
+          },
+          {
+            name: 'reviewedAt',
+            type: 'i64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'challengeTreasury',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'mintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'balance',
+            type: 'u64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'challengeTemplate',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'isEnabled',
+            type: 'bool',
+          },
+          {
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'minDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'maxDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'minDuration',
+            type: 'i64',
+          },
+          {
+            name: 'maxDuration',
+            type: 'i64',
+          },
+          {
+            name: 'payoutModel',
+            type: {
+              defined: 'PayoutModel',
+            },
+          },
+          {
+            name: 'feeBps',
+            type: 'u16',
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: 'AmendWinnerListParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'winnerList',
+            type: {
+              vec: 'publicKey',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'ApplyNoWinnerPolicyParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ApplyNoWinnerRolloverParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'sourceChallengeId',
+            type: 'string',
+          },
+          {
+            name: 'targetChallengeId',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'CancelChallengeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'CloseChallengeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'CloseDisputeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'CloseSubmissionParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'player',
+            type: 'publicKey',
+          },
+        ],
+      },
+    },
+    {
+      name: 'CreateChallengeFromTemplateParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'templateId',
+            type: 'string',
+          },
+          {
+            name: 'challenge',
+            type: {
+              defined: 'CreateChallengeParams',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'CreateChallengeSeriesParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'minDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'joinDuration',
+            type: 'i64',
+          },
+          {
+            name: 'duration',
+            type: 'i64',
+          },
+          {
+            name: 'rolloverEnabled',
+            type: 'bool',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ChallengeTemplateParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'isEnabled',
+            type: 'bool',
+          },
+          {
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'minDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'maxDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'minDuration',
+            type: 'i64',
+          },
+          {
+            name: 'maxDuration',
+            type: 'i64',
+          },
+          {
+            name: 'payoutModel',
+            type: {
+              defined: 'PayoutModel',
+            },
+          },
+          {
+            name: 'feeBps',
+            type: 'u16',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ChallengeMetadataParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'title',
+            type: 'string',
+          },
+          {
+            name: 'descriptionUri',
+            type: 'string',
+          },
+          {
+            name: 'category',
+            type: 'string',
+          },
+          {
+            name: 'rulesHash',
+            type: {
+              array: [
+                'u8',
+                32,
+              ],
+            },
+          },
+          {
+            name: 'coverImageUri',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'CreateChallengeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'minDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'joinDeadline',
+            type: 'i64',
+          },
+          {
+            name: 'endTime',
+            type: 'i64',
+          },
+          {
+            name: 'maxPlayers',
+            type: {
+              option: 'u64',
+            },
+          },
+          {
+            name: 'maxDepositPerPlayer',
+            type: {
+              option: 'u64',
+            },
+          },
+          {
+            name: 'maxPrizePool',
+            type: {
+              option: 'u64',
+            },
+          },
+          {
+            name: 'startTime',
+            type: {
+              option: 'i64',
+            },
+          },
+          {
+            name: 'earlyExitPenaltyBps',
+            type: 'u16',
+          },
+          {
+            name: 'milestones',
+            type: {
+              vec: 'i64',
+            },
+          },
+          {
+            name: 'judge',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'noWinnerPolicy',
+            type: {
+              defined: 'NoWinnerPolicy',
+            },
+          },
+          {
+            name: 'noWinnerRolloverTarget',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'payoutModel',
+            type: {
+              defined: 'PayoutModel',
+            },
+          },
+          {
+            name: 'payoutTable',
+            type: {
+              vec: 'u16',
+            },
+          },
+          {
+            name: 'payoutRemainderPolicy',
+            type: {
+              defined: 'PayoutRemainderPolicy',
+            },
+          },
+          {
+            name: 'creatorFeeBps',
+            type: 'u16',
+          },
+          {
+            name: 'metadata',
+            type: {
+              defined: 'ChallengeMetadataParams',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'ExpireChallengeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ForceCancelChallengeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'reasonCode',
+            type: 'u16',
+          },
+          {
+            name: 'reasonUri',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'InitializeChallengePlatformParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'allowedMintAccounts',
+            type: {
+              vec: {
+                defined: 'MintInfo',
+              },
+            },
+          },
+          {
+            name: 'allowedAdministrators',
+            type: {
+              vec: 'publicKey',
+            },
+          },
+          {
+            name: 'expiryGracePeriod',
+            type: 'i64',
+          },
+          {
+            name: 'disputeWindow',
+            type: 'i64',
+          },
+          {
+            name: 'correctionWindow',
+            type: 'i64',
+          },
+          {
+            name: 'protocolFeeBps',
+            type: 'u16',
+          },
+          {
+            name: 'maxCreatorFeeBps',
+            type: 'u16',
+          },
+          {
+            name: 'referralBps',
+            type: 'u16',
+          },
+          {
+            name: 'referralFeeSource',
+            type: {
+              defined: 'ReferralFeeSource',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'OpenDisputeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'reasonUri',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'RecordCheckInParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'player',
+            type: 'publicKey',
+          },
+          {
+            name: 'milestoneIndex',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ResolveDisputeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'actionType',
             type: {
-              vec: {
-                defined: 'MintInfo';
-              };
-            };
+              defined: 'ResolveDisputeActionType',
+            },
           },
           {
-            name: 'allowedAdministrators';
+            name: 'winnerList',
             type: {
-              vec: 'publicKey';
-            };
+              vec: 'publicKey',
+            },
           },
-        ];
-      };
+        ],
+      },
     },
     {
-      name: 'SubmitWinnersParams';
+      name: 'ReviewProofParams',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'id';
-            type: 'string';
+            name: 'challengeId',
+            type: 'string',
           },
           {
-            name: 'winnerList';
+            name: 'player',
+            type: 'publicKey',
+          },
+          {
+            name: 'actionType',
             type: {
-              vec: 'publicKey';
-            };
+              defined: 'ReviewProofActionType',
+            },
           },
-        ];
-      };
+        ],
+      },
     },
     {
-      name: 'TransferAssetsFromVaultParams';
+      name: 'SetMintProtocolFeeParams',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'challengeTokenVaultBump';
-            type: 'u8';
+            name: 'mintAccount',
+            type: 'publicKey',
           },
           {
-            name: 'challengeId';
-            type: 'string';
+            name: 'protocolFeeBps',
+            type: {
+              option: 'u16',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'SettleMilestoneChallengeParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'SpawnSeriesRoundParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'seriesId',
+            type: 'string',
           },
           {
-            name: 'actionType';
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'metadata',
             type: {
-              defined: 'TransferAssetsFromVaultActionType';
-            };
+              defined: 'ChallengeMetadataParams',
+            },
           },
-        ];
-      };
+        ],
+      },
     },
     {
-      name: 'TransferAssetsToVaultParams';
+      name: 'SubmitProofParams',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'challengeTokenVaultBump';
-            type: 'u8';
+            name: 'challengeId',
+            type: 'string',
           },
           {
-            name: 'challengeId';
-            type: 'string';
+            name: 'evidenceUri',
+            type: 'string',
           },
           {
-            name: 'actionType';
+            name: 'contentHash',
             type: {
-              defined: 'TransferAssetsToVaultActionType';
-            };
+              array: [
+                'u8',
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'SubmitWinnersParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
           },
           {
-            name: 'amount';
-            type: 'u64';
+            name: 'winnerList',
+            type: {
+              vec: 'publicKey',
+            },
           },
-        ];
-      };
+        ],
+      },
     },
     {
-      name: 'UpdateChallengePlatformParams';
+      name: 'TransferAssetsFromVaultParams',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'allowedMintAccounts';
+            name: 'challengeTokenVaultBump',
+            type: 'u8',
+          },
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'actionType',
             type: {
-              vec: {
-                defined: 'MintInfo';
-              };
-            };
+              defined: 'TransferAssetsFromVaultActionType',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'TransferAssetsToVaultParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeTokenVaultBump',
+            type: 'u8',
           },
           {
-            name: 'allowedAdministrators';
+            name: 'challengeId',
+            type: 'string',
+          },
+          {
+            name: 'actionType',
             type: {
-              vec: 'publicKey';
-            };
+              defined: 'TransferAssetsToVaultActionType',
+            },
           },
-        ];
-      };
+          {
+            name: 'amount',
+            type: 'u64',
+          },
+          {
+            name: 'referrer',
+            type: {
+              option: 'publicKey',
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'MintInfo';
+      name: 'TransitionChallengeStatusParams',
       type: {
-        kind: 'struct';
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeId',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'UpdateChallengeFromTemplateParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'templateId',
+            type: 'string',
+          },
+          {
+            name: 'challenge',
+            type: {
+              defined: 'UpdateChallengeParams',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'UpdateChallengeMetadataParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'string',
+          },
+          {
+            name: 'metadata',
+            type: {
+              defined: 'ChallengeMetadataParams',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'UpdateChallengePlatformParams',
+      type: {
+        kind: 'struct',
         fields: [
           {
-            name: 'isEnabled';
-            type: 'bool';
+            name: 'allowedMintAccounts',
+            type: {
+              vec: {
+                defined: 'MintInfo',
+              },
+            },
+          },
+          {
+            name: 'allowedAdministrators',
+            type: {
+              vec: 'publicKey',
+            },
+          },
+          {
+            name: 'expiryGracePeriod',
+            type: 'i64',
+          },
+          {
+            name: 'disputeWindow',
+            type: 'i64',
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'correctionWindow',
+            type: 'i64',
           },
           {
-            name: 'mintAccount';
-            type: 'publicKey';
+            name: 'protocolFeeBps',
+            type: 'u16',
           },
           {
-            name: 'tokenAccount';
-            type: 'publicKey';
+            name: 'maxCreatorFeeBps',
+            type: 'u16',
           },
-        ];
-      };
+          {
+            name: 'referralBps',
+            type: 'u16',
+          },
+          {
+            name: 'referralFeeSource',
+            type: {
+              defined: 'ReferralFeeSource',
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'PlayerInfo';
+      name: 'UpdateChallengeParams',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'publicKey';
-            type: 'publicKey';
+            name: 'id',
+            type: 'string',
           },
           {
-            name: 'totalDeposit';
-            type: 'u64';
+            name: 'minDeposit',
+            type: 'u64',
           },
           {
-            name: 'isWinner';
-            type: 'bool';
+            name: 'rewardTokenMintAccount',
+            type: 'publicKey',
           },
           {
-            name: 'isWinnerClaimedReward';
-            type: 'bool';
+            name: 'joinDeadline',
+            type: 'i64',
           },
           {
-            name: 'isPlayerWithdrawn';
-            type: 'bool';
+            name: 'endTime',
+            type: 'i64',
           },
-        ];
-      };
-    },
-    {
-      name: 'TransferAssetsFromVaultActionType';
-      type: {
-        kind: 'enum';
-        variants: [
           {
-            name: 'Claiming';
+            name: 'maxPlayers',
+            type: {
+              option: 'u64',
+            },
           },
           {
-            name: 'Withdrawing';
+            name: 'maxDepositPerPlayer',
+            type: {
+              option: 'u64',
+            },
           },
           {
-            name: 'AdminWithdrawingDonatePool';
+            name: 'maxPrizePool',
+            type: {
+              option: 'u64',
+            },
           },
-        ];
-      };
-    },
-    {
-      name: 'TransferAssetsToVaultActionType';
-      type: {
-        kind: 'enum';
-        variants: [
           {
-            name: 'JoinChallenge';
+            name: 'startTime',
+            type: {
+              option: 'i64',
+            },
           },
           {
-            name: 'Donate';
+            name: 'earlyExitPenaltyBps',
+            type: 'u16',
           },
-        ];
-      };
-    },
-    {
-      name: 'ChallengeStatus';
-      type: {
-        kind: 'enum';
-        variants: [
           {
-            name: 'Created';
+            name: 'milestones',
+            type: {
+              vec: 'i64',
+            },
           },
           {
-            name: 'Finalized';
+            name: 'judge',
+            type: {
+              option: 'publicKey',
+            },
           },
           {
-            name: 'Canceled';
+            name: 'noWinnerPolicy',
+            type: {
+              defined: 'NoWinnerPolicy',
+            },
           },
           {
-            name: 'Claimed';
+            name: 'noWinnerRolloverTarget',
+            type: {
+              option: 'publicKey',
+            },
           },
           {
-            name: 'Withdrawn';
+            name: 'payoutModel',
+            type: {
+              defined: 'PayoutModel',
+            },
           },
-        ];
-      };
-    },
-  ];
-  events: [
-    {
-      name: 'ChallengeRegistryUpdated';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'allowedAdministrators';
-          type: {
-            vec: 'publicKey';
-          };
-          index: false;
-        },
-        {
-          name: 'allowedMintAccounts';
-          type: {
-            vec: {
-              defined: 'MintInfo';
-            };
-          };
-          index: false;
-        },
-      ];
-    },
-    {
-      name: 'VaultCreated';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'authority';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'mintAccount';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'associatedAccount';
-          type: 'publicKey';
-          index: true;
-        },
-      ];
-    },
-    {
-      name: 'ChallengeCreated';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeKey';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'id';
-          type: 'string';
-          index: true;
-        },
-      ];
-    },
-    {
-      name: 'ChallengeFinalized';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeKey';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'id';
-          type: 'string';
-          index: true;
-        },
-        {
-          name: 'status';
-          type: {
-            defined: 'ChallengeStatus';
-          };
-          index: false;
-        },
-      ];
-    },
-    {
-      name: 'ChallengeCanceled';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeKey';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'id';
-          type: 'string';
-          index: true;
-        },
-        {
-          name: 'status';
-          type: {
-            defined: 'ChallengeStatus';
-          };
-          index: false;
-        },
-      ];
-    },
-    {
-      name: 'RewardReceived';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeKey';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeId';
-          type: 'string';
-          index: true;
-        },
-        {
-          name: 'rewardMintToken';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'actionType';
-          type: {
-            defined: 'TransferAssetsToVaultActionType';
-          };
-          index: false;
-        },
-        {
-          name: 'amount';
-          type: 'u64';
-          index: false;
-        },
-      ];
+          {
+            name: 'payoutTable',
+            type: {
+              vec: 'u16',
+            },
+          },
+          {
+            name: 'payoutRemainderPolicy',
+            type: {
+              defined: 'PayoutRemainderPolicy',
+            },
+          },
+          {
+            name: 'creatorFeeBps',
+            type: 'u16',
+          },
+        ],
+      },
     },
     {
-      name: 'RewardClaimed';
-      fields: [
-        {
-          name: 'actor';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeKey';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'challengeId';
-          type: 'string';
-          index: true;
-        },
-        {
-          name: 'rewardMintToken';
-          type: 'publicKey';
-          index: true;
-        },
-        {
-          name: 'actionType';
-          type: {
-            defined: 'TransferAssetsFromVaultActionType';
-          };
-          index: false;
-        },
-        {
-          name: 'amount';
-          type: 'u64';
-          index: false;
-        },
-      ];
+      name: 'WithdrawFeesParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'challengeTokenVaultBump',
+            type: 'u8',
+          },
+          {
+            name: 'amount',
+            type: 'u64',
+          },
+        ],
+      },
     },
-  ];
-  errors: [
     {
-      code: 6000;
-      name: 'AlreadyInitialized';
-      msg: 'The program was already initialized';
+      name: 'MintInfo',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'isEnabled',
+            type: 'bool',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'mintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'tokenAccount',
+            type: 'publicKey',
+          },
+        ],
+      },
     },
     {
-      code: 6001;
-      name: 'MintAccountExisted';
-      msg: 'The mint account was existed';
+      name: 'MintProtocolFee',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'mintAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'protocolFeeBps',
+            type: 'u16',
+          },
+        ],
+      },
     },
     {
-      code: 6002;
-      name: 'OnlyAdministrator';
-      msg: 'Only Platform Admin';
+      name: 'PlayerInfo',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'publicKey',
+            type: 'publicKey',
+          },
+          {
+            name: 'totalDeposit',
+            type: 'u64',
+          },
+          {
+            name: 'isWinner',
+            type: 'bool',
+          },
+          {
+            name: 'isWinnerClaimedReward',
+            type: 'bool',
+          },
+          {
+            name: 'isPlayerWithdrawn',
+            type: 'bool',
+          },
+          {
+            name: 'checkedInMilestones',
+            type: 'u32',
+          },
+          {
+            name: 'isEliminated',
+            type: 'bool',
+          },
+          {
+            name: 'rank',
+            type: 'u8',
+          },
+          {
+            name: 'referrerIndex',
+            type: {
+              option: 'u8',
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6003;
-      name: 'OnlyOwner';
-      msg: 'Only Owner';
+      name: 'DonorInfo',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'publicKey',
+            type: 'publicKey',
+          },
+          {
+            name: 'totalDonation',
+            type: 'u64',
+          },
+          {
+            name: 'isDonationRefunded',
+            type: 'bool',
+          },
+        ],
+      },
     },
     {
-      code: 6004;
-      name: 'OrderExpired';
-      msg: 'Order expired';
+      name: 'ReferrerInfo',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'publicKey',
+            type: 'publicKey',
+          },
+          {
+            name: 'referralReward',
+            type: 'u64',
+          },
+          {
+            name: 'isReferralRewardClaimed',
+            type: 'bool',
+          },
+        ],
+      },
     },
     {
-      code: 6005;
-      name: 'InvalidOffer';
-      msg: 'Invalid Offer';
+      name: 'ResolveDisputeActionType',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'UpholdWinnerList',
+          },
+          {
+            name: 'ReplaceWinnerList',
+          },
+        ],
+      },
     },
     {
-      code: 6006;
-      name: 'InvalidValue';
-      msg: 'Invalid value';
+      name: 'ReviewProofActionType',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'AcceptProof',
+          },
+          {
+            name: 'RejectProof',
+          },
+        ],
+      },
     },
     {
-      code: 6007;
-      name: 'UnAllowedMintToken';
-      msg: 'Invalid value';
+      name: 'TransferAssetsFromVaultActionType',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Claiming',
+          },
+          {
+            name: 'Withdrawing',
+          },
+          {
+            name: 'AdminWithdrawingDonatePool',
+          },
+          {
+            name: 'LeavingChallenge',
+          },
+          {
+            name: 'RefundingDonation',
+          },
+          {
+            name: 'ClaimingCreatorFee',
+          },
+          {
+            name: 'ClaimingReferralReward',
+          },
+        ],
+      },
     },
     {
-      code: 6008;
-      name: 'ChallengeCannotBeCanceled';
-      msg: 'Challenge cannot be canceled';
+      name: 'TransferAssetsToVaultActionType',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'JoinChallenge',
+          },
+          {
+            name: 'Donate',
+          },
+        ],
+      },
     },
     {
-      code: 6009;
-      name: 'WithdrawalIsNotAvailable';
-      msg: 'Withdrawal is not available for the challenge';
+      name: 'ChallengeStatus',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Open',
+          },
+          {
+            name: 'Finalized',
+          },
+          {
+            name: 'Canceled',
+          },
+          {
+            name: 'Claimed',
+          },
+          {
+            name: 'Withdrawn',
+          },
+          {
+            name: 'PendingResult',
+          },
+          {
+            name: 'Disputed',
+          },
+          {
+            name: 'Upcoming',
+          },
+          {
+            name: 'Running',
+          },
+        ],
+      },
     },
     {
-      code: 6010;
-      name: 'ClaimIsNotAvailable';
-      msg: 'Claim is not available for the challenge';
+      name: 'NoWinnerPolicy',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'RefundPlayers',
+          },
+          {
+            name: 'SendToTreasury',
+          },
+          {
+            name: 'RollOver',
+          },
+        ],
+      },
     },
     {
-      code: 6011;
-      name: 'TransferTokenFromVaultIsNotAvailable';
-      msg: 'Transfer token from vault is not available for the challenge';
+      name: 'PayoutModel',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'EqualSplit',
+          },
+          {
+            name: 'Ranked',
+          },
+          {
+            name: 'StakeProportional',
+          },
+          {
+            name: 'StakeBack',
+          },
+        ],
+      },
     },
     {
-      code: 6012;
-      name: 'DepositIsNotAvailable';
-      msg: 'Deposit is not available for the challenge';
+      name: 'PayoutRemainderPolicy',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'LastClaimer',
+          },
+          {
+            name: 'TopRankedWinner',
+          },
+          {
+            name: 'Treasury',
+          },
+        ],
+      },
     },
     {
-      code: 6013;
-      name: 'OnlyParticipant';
-      msg: 'Only participants can execute this operation';
+      name: 'ReferralFeeSource',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'ProtocolFee',
+          },
+          {
+            name: 'CreatorFee',
+          },
+        ],
+      },
     },
     {
-      code: 6014;
-      name: 'AlreadyParticipated';
-      msg: 'The participant already participated in the challenge.';
+      name: 'DisputeStatus',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Opened',
+          },
+          {
+            name: 'Upheld',
+          },
+          {
+            name: 'Replaced',
+          },
+        ],
+      },
     },
     {
-      code: 6015;
-      name: 'MinDepositIsNotReached';
-      msg: 'Min deposit amount is not reached';
+      name: 'SubmissionStatus',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Pending',
+          },
+          {
+            name: 'Accepted',
+          },
+          {
+            name: 'Rejected',
+          },
+        ],
+      },
     },
-  ];
-};
-
-export const IDL: Challenge = {
-  version: '0.1.0',
-  name: 'challenge',
-  instructions: [
+  ],
+  events: [
     {
-      name: 'initialize',
-      accounts: [
-        {
-          name: 'owner',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'challengeRegistry',
-          isMut: true,
-          isSigner: false,
-        },
+      name: 'ChallengeRegistryUpdated',
+      fields: [
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
-      ],
-      args: [
         {
-          name: 'params',
+          name: 'allowedAdministrators',
           type: {
-            defined: 'InitializeChallengePlatformParams',
+            vec: 'publicKey',
           },
+          index: false,
         },
-      ],
-    },
-    {
-      name: 'updateChallengeRegistry',
-      accounts: [
-        {
-          name: 'owner',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'challengeRegistry',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
         {
-          name: 'params',
+          name: 'allowedMintAccounts',
           type: {
-            defined: 'UpdateChallengePlatformParams',
+            vec: {
+              defined: 'MintInfo',
+            },
           },
-        },
-      ],
-    },
-    {
-      name: 'createTokenVault',
-      accounts: [
-        {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'challengeRegistry',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'mintAccount',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'challengeTokenVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          index: false,
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'expiryGracePeriod',
+          type: 'i64',
+          index: false,
         },
         {
-          name: 'rent',
-          isMut: false,
-          isSigner: false,
+          name: 'disputeWindow',
+          type: 'i64',
+          index: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: 'createChallenge',
-      accounts: [
         {
-          name: 'challengeOwner',
-          isMut: true,
-          isSigner: true,
+          name: 'correctionWindow',
+          type: 'i64',
+          index: false,
         },
         {
-          name: 'challenge',
-          isMut: true,
-          isSigner: false,
+          name: 'protocolFeeBps',
+          type: 'u16',
+          index: false,
         },
         {
-          name: 'challengeRegistry',
-          isMut: false,
-          isSigner: false,
+          name: 'maxCreatorFeeBps',
+          type: 'u16',
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'referralBps',
+          type: 'u16',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'params',
+          name: 'referralFeeSource',
           type: {
-            defined: 'CreateChallengeParams',
+            defined: 'ReferralFeeSource',
           },
+          index: false,
         },
       ],
     },
     {
-      name: 'cancelChallenge',
-      accounts: [
+      name: 'VaultCreated',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'challenge',
-          isMut: true,
-          isSigner: false,
+          name: 'authority',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
+          name: 'mintAccount',
+          type: 'publicKey',
+          index: true,
+        },
         {
-          name: 'params',
-          type: {
-            defined: 'CancelChallengeParams',
-          },
+          name: 'associatedAccount',
+          type: 'publicKey',
+          index: true,
         },
       ],
     },
     {
-      name: 'transferAssetsToVault',
-      accounts: [
+      name: 'ChallengeCreated',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'mintAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'signerTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'id',
+          type: 'string',
+          index: true,
         },
+      ],
+    },
+    {
+      name: 'ChallengeUpdated',
+      fields: [
         {
-          name: 'challenge',
-          isMut: true,
-          isSigner: false,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'challengeTokenVault',
-          isMut: true,
-          isSigner: false,
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'id',
+          type: 'string',
+          index: true,
         },
+      ],
+    },
+    {
+      name: 'ChallengeMetadataUpdated',
+      fields: [
         {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
-      ],
-      args: [
         {
-          name: 'params',
-          type: {
-            defined: 'TransferAssetsToVaultParams',
-          },
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'metadataKey',
+          type: 'publicKey',
+          index: true,
         },
       ],
     },
     {
-      name: 'transferAssetsFromVault',
-      accounts: [
+      name: 'WinnerListSubmitted',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'mintAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'challengeRegistry',
-          isMut: false,
-          isSigner: false,
+          name: 'id',
+          type: 'string',
+          index: true,
         },
         {
-          name: 'signerTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'winnerList',
+          type: {
+            vec: 'publicKey',
+          },
+          index: false,
         },
         {
-          name: 'challenge',
-          isMut: true,
-          isSigner: false,
+          name: 'disputeDeadline',
+          type: 'i64',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'DisputeOpened',
+      fields: [
         {
-          name: 'challengeTokenVault',
-          isMut: true,
-          isSigner: false,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'disputeKey',
+          type: 'publicKey',
+          index: true,
         },
-      ],
-      args: [
         {
-          name: 'params',
-          type: {
-            defined: 'TransferAssetsFromVaultParams',
-          },
+          name: 'reasonUri',
+          type: 'string',
+          index: false,
         },
       ],
     },
     {
-      name: 'submitWinnerList',
-      accounts: [
+      name: 'DisputeResolved',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'challengeRegistry',
-          isMut: false,
-          isSigner: false,
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'challenge',
-          isMut: true,
-          isSigner: false,
+          name: 'disputeKey',
+          type: 'publicKey',
+          index: true,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'status',
+          type: {
+            defined: 'DisputeStatus',
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'params',
+          name: 'winnerList',
           type: {
-            defined: 'SubmitWinnersParams',
+            vec: 'publicKey',
           },
+          index: false,
         },
-      ],
-    },
-  ],
-  accounts: [
-    {
-      name: 'challengePlatformRegistry',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'owner',
-            type: 'publicKey',
-          },
-          {
-            name: 'wasInitialized',
-            type: 'bool',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-          {
-            name: 'allowedAdministrators',
-            type: {
-              vec: 'publicKey',
-            },
-          },
-          {
-            name: 'allowedMintAccounts',
-            type: {
-              vec: {
-                defined: 'MintInfo',
-              },
-            },
-          },
-        ],
-      },
-    },
-    {
-      name: 'challenge',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'id',
-            type: 'string',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-          {
-            name: 'owner',
-            type: 'publicKey',
-          },
-          {
-            name: 'minDeposit',
-            type: 'u64',
-          },
-          {
-            name: 'players',
-            type: {
-              vec: {
-                defined: 'PlayerInfo',
-              },
-            },
-          },
-          {
-            name: 'prizePool',
-            type: 'u64',
-          },
-          {
-            name: 'donatePool',
-            type: 'u64',
-          },
-          {
-            name: 'rewardTokenMintAccount',
-            type: 'publicKey',
-          },
-          {
-            name: 'status',
-            type: {
-              defined: 'ChallengeStatus',
-            },
-          },
-        ],
-      },
+      ],
     },
-  ],
-  types: [
     {
-      name: 'CancelChallengeParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'id',
-            type: 'string',
+      name: 'ChallengeFinalized',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'id',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'status',
+          type: {
+            defined: 'ChallengeStatus',
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: 'CreateChallengeParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'id',
-            type: 'string',
-          },
-          {
-            name: 'minDeposit',
-            type: 'u64',
-          },
-          {
-            name: 'rewardTokenMintAccount',
-            type: 'publicKey',
+      name: 'ChallengeCanceled',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'id',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'status',
+          type: {
+            defined: 'ChallengeStatus',
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: 'InitializeChallengePlatformParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'allowedMintAccounts',
-            type: {
-              vec: {
-                defined: 'MintInfo',
-              },
-            },
-          },
-          {
-            name: 'allowedAdministrators',
-            type: {
-              vec: 'publicKey',
-            },
+      name: 'ChallengeExpired',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'id',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'status',
+          type: {
+            defined: 'ChallengeStatus',
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: 'SubmitWinnersParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'id',
-            type: 'string',
-          },
-          {
-            name: 'winnerList',
-            type: {
-              vec: 'publicKey',
-            },
+      name: 'RewardReceived',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeId',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'rewardMintToken',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'actionType',
+          type: {
+            defined: 'TransferAssetsToVaultActionType',
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: 'amount',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'TransferAssetsFromVaultParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'challengeTokenVaultBump',
-            type: 'u8',
-          },
-          {
-            name: 'challengeId',
-            type: 'string',
-          },
-          {
-            name: 'actionType',
-            type: {
-              defined: 'TransferAssetsFromVaultActionType',
-            },
+      name: 'RewardClaimed',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeId',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'rewardMintToken',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'actionType',
+          type: {
+            defined: 'TransferAssetsFromVaultActionType',
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: 'amount',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'TransferAssetsToVaultParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'challengeTokenVaultBump',
-            type: 'u8',
-          },
-          {
-            name: 'challengeId',
-            type: 'string',
-          },
-          {
-            name: 'actionType',
-            type: {
-              defined: 'TransferAssetsToVaultActionType',
-            },
-          },
-          {
-            name: 'amount',
-            type: 'u64',
-          },
-        ],
-      },
+      name: 'PlayerLeft',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeId',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'refundAmount',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'penaltyAmount',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'UpdateChallengePlatformParams',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'allowedMintAccounts',
-            type: {
-              vec: {
-                defined: 'MintInfo',
-              },
-            },
-          },
-          {
-            name: 'allowedAdministrators',
-            type: {
-              vec: 'publicKey',
-            },
-          },
-        ],
-      },
+      name: 'ChallengeSeriesCreated',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'seriesKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'id',
+          type: 'string',
+          index: true,
+        },
+      ],
     },
     {
-      name: 'MintInfo',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'isEnabled',
-            type: 'bool',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-          {
-            name: 'mintAccount',
-            type: 'publicKey',
-          },
-          {
-            name: 'tokenAccount',
-            type: 'publicKey',
-          },
-        ],
-      },
+      name: 'SeriesRoundSpawned',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'seriesKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'round',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'PlayerInfo',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'publicKey',
-            type: 'publicKey',
-          },
-          {
-            name: 'totalDeposit',
-            type: 'u64',
-          },
-          {
-            name: 'isWinner',
-            type: 'bool',
-          },
-          {
-            name: 'isWinnerClaimedReward',
-            type: 'bool',
-          },
-          {
-            name: 'isPlayerWithdrawn',
-            type: 'bool',
-          },
-        ],
-      },
+      name: 'PrizePoolRolledOver',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'sourceChallengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'targetChallengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'amount',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'TransferAssetsFromVaultActionType',
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'Claiming',
-          },
-          {
-            name: 'Withdrawing',
-          },
-          {
-            name: 'AdminWithdrawingDonatePool',
-          },
-        ],
-      },
+      name: 'CheckInRecorded',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'player',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'milestoneIndex',
+          type: 'u8',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'TransferAssetsToVaultActionType',
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'JoinChallenge',
-          },
-          {
-            name: 'Donate',
-          },
-        ],
-      },
+      name: 'PlayerEliminated',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'player',
+          type: 'publicKey',
+          index: true,
+        },
+      ],
     },
     {
-      name: 'ChallengeStatus',
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'Created',
-          },
-          {
-            name: 'Finalized',
-          },
-          {
-            name: 'Canceled',
+      name: 'ProofSubmitted',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'submissionKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'evidenceUri',
+          type: 'string',
+          index: false,
+        },
+        {
+          name: 'contentHash',
+          type: {
+            array: [
+              'u8',
+              32,
+            ],
           },
-          {
-            name: 'Claimed',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'ProofReviewed',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'submissionKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'player',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'status',
+          type: {
+            defined: 'SubmissionStatus',
           },
-          {
-            name: 'Withdrawn',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'NoWinnerPolicyApplied',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'policy',
+          type: {
+            defined: 'NoWinnerPolicy',
           },
-        ],
-      },
+          index: true,
+        },
+        {
+          name: 'amount',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: 'ChallengeRegistryUpdated',
+      name: 'WinnerListAmended',
       fields: [
         {
           name: 'actor',
@@ -1560,25 +7794,33 @@ export const IDL: Challenge = {
           index: true,
         },
         {
-          name: 'allowedAdministrators',
+          name: 'challengeKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'id',
+          type: 'string',
+          index: true,
+        },
+        {
+          name: 'previousWinnerList',
           type: {
             vec: 'publicKey',
           },
           index: false,
         },
         {
-          name: 'allowedMintAccounts',
+          name: 'winnerList',
           type: {
-            vec: {
-              defined: 'MintInfo',
-            },
+            vec: 'publicKey',
           },
           index: false,
         },
       ],
     },
     {
-      name: 'VaultCreated',
+      name: 'ChallengeTemplateUpdated',
       fields: [
         {
           name: 'actor',
@@ -1586,24 +7828,24 @@ export const IDL: Challenge = {
           index: true,
         },
         {
-          name: 'authority',
+          name: 'templateKey',
           type: 'publicKey',
           index: true,
         },
         {
-          name: 'mintAccount',
-          type: 'publicKey',
+          name: 'id',
+          type: 'string',
           index: true,
         },
         {
-          name: 'associatedAccount',
-          type: 'publicKey',
-          index: true,
+          name: 'isEnabled',
+          type: 'bool',
+          index: false,
         },
       ],
     },
     {
-      name: 'ChallengeCreated',
+      name: 'ChallengeStatusChanged',
       fields: [
         {
           name: 'actor',
@@ -1620,10 +7862,24 @@ export const IDL: Challenge = {
           type: 'string',
           index: true,
         },
+        {
+          name: 'from',
+          type: {
+            defined: 'ChallengeStatus',
+          },
+          index: false,
+        },
+        {
+          name: 'to',
+          type: {
+            defined: 'ChallengeStatus',
+          },
+          index: false,
+        },
       ],
     },
     {
-      name: 'ChallengeFinalized',
+      name: 'ChallengeClosed',
       fields: [
         {
           name: 'actor',
@@ -1640,6 +7896,11 @@ export const IDL: Challenge = {
           type: 'string',
           index: true,
         },
+        {
+          name: 'owner',
+          type: 'publicKey',
+          index: false,
+        },
         {
           name: 'status',
           type: {
@@ -1647,10 +7908,67 @@ export const IDL: Challenge = {
           },
           index: false,
         },
+        {
+          name: 'rewardTokenMintAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'totalPlayers',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'totalWinners',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'totalDeposit',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'prizePool',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'collectedFee',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'reclaimedLamports',
+          type: 'u64',
+          index: false,
+        },
       ],
     },
     {
-      name: 'ChallengeCanceled',
+      name: 'MintProtocolFeeUpdated',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'mintAccount',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'protocolFeeBps',
+          type: {
+            option: 'u16',
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'DisputeClosed',
       fields: [
         {
           name: 'actor',
@@ -1663,21 +7981,19 @@ export const IDL: Challenge = {
           index: true,
         },
         {
-          name: 'id',
-          type: 'string',
+          name: 'disputeKey',
+          type: 'publicKey',
           index: true,
         },
         {
-          name: 'status',
-          type: {
-            defined: 'ChallengeStatus',
-          },
+          name: 'disputer',
+          type: 'publicKey',
           index: false,
         },
       ],
     },
     {
-      name: 'RewardReceived',
+      name: 'SubmissionClosed',
       fields: [
         {
           name: 'actor',
@@ -1690,20 +8006,38 @@ export const IDL: Challenge = {
           index: true,
         },
         {
-          name: 'challengeId',
-          type: 'string',
+          name: 'submissionKey',
+          type: 'publicKey',
           index: true,
         },
         {
-          name: 'rewardMintToken',
+          name: 'player',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'FeesWithdrawn',
+      fields: [
+        {
+          name: 'actor',
           type: 'publicKey',
           index: true,
         },
         {
-          name: 'actionType',
-          type: {
-            defined: 'TransferAssetsToVaultActionType',
-          },
+          name: 'treasuryKey',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'mintAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'recipientTokenAccount',
+          type: 'publicKey',
           index: false,
         },
         {
@@ -1714,7 +8048,7 @@ export const IDL: Challenge = {
       ],
     },
     {
-      name: 'RewardClaimed',
+      name: 'TreasuryCreated',
       fields: [
         {
           name: 'actor',
@@ -1722,25 +8056,38 @@ export const IDL: Challenge = {
           index: true,
         },
         {
-          name: 'challengeKey',
+          name: 'treasuryKey',
           type: 'publicKey',
           index: true,
         },
         {
-          name: 'challengeId',
-          type: 'string',
+          name: 'mintAccount',
+          type: 'publicKey',
+          index: true,
+        },
+      ],
+    },
+    {
+      name: 'ReferralRecorded',
+      fields: [
+        {
+          name: 'actor',
+          type: 'publicKey',
           index: true,
         },
         {
-          name: 'rewardMintToken',
+          name: 'challengeKey',
           type: 'publicKey',
           index: true,
         },
         {
-          name: 'actionType',
-          type: {
-            defined: 'TransferAssetsFromVaultActionType',
-          },
+          name: 'referrer',
+          type: 'publicKey',
+          index: true,
+        },
+        {
+          name: 'challengeId',
+          type: 'string',
           index: false,
         },
         {
@@ -1832,6 +8179,131 @@ export const IDL: Challenge = {
       name: 'MinDepositIsNotReached',
       msg: 'Min deposit amount is not reached',
     },
+    {
+      code: 6016,
+      name: 'JoiningClosed',
+      msg: 'Joining is closed for the challenge',
+    },
+    {
+      code: 6017,
+      name: 'ChallengeNotEndedYet',
+      msg: 'The challenge has not ended yet',
+    },
+    {
+      code: 6018,
+      name: 'ChallengeCannotBeExpired',
+      msg: 'Challenge cannot be expired',
+    },
+    {
+      code: 6019,
+      name: 'DisputeIsNotAvailable',
+      msg: 'Dispute is not available for the challenge',
+    },
+    {
+      code: 6020,
+      name: 'DisputeAlreadyResolved',
+      msg: 'The dispute was already resolved',
+    },
+    {
+      code: 6021,
+      name: 'MaxPlayersReached',
+      msg: 'Max players of the challenge is reached',
+    },
+    {
+      code: 6022,
+      name: 'MaxDepositPerPlayerExceeded',
+      msg: 'Max deposit per player is exceeded',
+    },
+    {
+      code: 6023,
+      name: 'MaxPrizePoolExceeded',
+      msg: 'Max prize pool of the challenge is exceeded',
+    },
+    {
+      code: 6024,
+      name: 'ChallengeCannotBeUpdated',
+      msg: 'Challenge cannot be updated',
+    },
+    {
+      code: 6025,
+      name: 'LeavingIsNotAvailable',
+      msg: 'Leaving is not available for the challenge',
+    },
+    {
+      code: 6026,
+      name: 'RolloverIsNotAvailable',
+      msg: 'Rollover is not available for the challenge',
+    },
+    {
+      code: 6027,
+      name: 'CheckInIsNotAvailable',
+      msg: 'Check-in is not available for the milestone',
+    },
+    {
+      code: 6028,
+      name: 'PlayerEliminated',
+      msg: 'The player was eliminated from the challenge',
+    },
+    {
+      code: 6029,
+      name: 'SubmissionIsNotAvailable',
+      msg: 'Proof submission is not available for the challenge',
+    },
+    {
+      code: 6030,
+      name: 'SubmissionAlreadyReviewed',
+      msg: 'The submission was already reviewed',
+    },
+    {
+      code: 6031,
+      name: 'NoWinnerPolicyIsNotAvailable',
+      msg: 'No-winner policy is not available for the challenge',
+    },
+    {
+      code: 6032,
+      name: 'WinnerListCannotBeAmended',
+      msg: 'The winner list cannot be amended',
+    },
+    {
+      code: 6033,
+      name: 'TemplateIsNotAvailable',
+      msg: 'The challenge template is not available',
+    },
+    {
+      code: 6034,
+      name: 'TemplateConstraintViolated',
+      msg: 'The challenge settings violate the template',
+    },
+    {
+      code: 6035,
+      name: 'StatusTransitionIsNotAvailable',
+      msg: 'The challenge status is already up to date',
+    },
+    {
+      code: 6036,
+      name: 'MaxDonorsReached',
+      msg: 'Max donors of the challenge is reached',
+    },
+    {
+      code: 6037,
+      name: 'DonationRefundIsNotAvailable',
+      msg: 'Donation refund is not available',
+    },
+    {
+      code: 6038,
+      name: 'ChallengeCannotBeClosed',
+      msg: 'The challenge still has outstanding liabilities',
+    },
+    {
+      code: 6039,
+      name: 'StatusTransitionIsNotAllowed',
+      msg: 'The challenge status transition is not allowed',
+    },
+    {
+      code: 6040,
+      name: 'MaxReferrersReached',
+      msg: 'Max referrers of the challenge is reached',
+    },
   ],
 };
 
@@ -1850,3 +8322,27 @@ export type ChallengePlatformRegistryState = Awaited<
 export type ChallengeState = Awaited<
   ReturnType<Program<Challenge>['account']['challenge']['fetch']>
 >;
+
+export type ChallengeMetadataState = Awaited<
+  ReturnType<Program<Challenge>['account']['challengeMetadata']['fetch']>
+>;
+
+export type ChallengeSeriesState = Awaited<
+  ReturnType<Program<Challenge>['account']['challengeSeries']['fetch']>
+>;
+
+export type ChallengeDisputeState = Awaited<
+  ReturnType<Program<Challenge>['account']['challengeDispute']['fetch']>
+>;
+
+export type ChallengeSubmissionState = Awaited<
+  ReturnType<Program<Challenge>['account']['challengeSubmission']['fetch']>
+>;
+
+export type ChallengeTreasuryState = Awaited<
+  ReturnType<Program<Challenge>['account']['challengeTreasury']['fetch']>
+>;
+
+export type ChallengeTemplateState = Awaited<
+  ReturnType<Program<Challenge>['account']['challengeTemplate']['fetch']>
+>;
@@ -7,6 +7,90 @@ import { BN } from '@project-serum/anchor';
 import { ChallengeProgram, ProgramBuilder } from './program.builder';
 import { PDAFinder } from './program.finder';
 import { ChallengeProgramState } from './challenge.state';
+import { ChallengePlatformRegistryState } from './challenge.idl';
+
+/**
+ * @dev Define the descriptive metadata of a challenge.
+ */
+export interface ChallengeMetadataPayload {
+  title: string;
+  descriptionUri: string;
+  category: string;
+  rulesHash: number[];
+  coverImageUri: string;
+}
+
+/**
+ * @dev Define the empty metadata.
+ */
+const EMPTY_METADATA: ChallengeMetadataPayload = {
+  title: '',
+  descriptionUri: '',
+  category: '',
+  rulesHash: new Array(32).fill(0),
+  coverImageUri: '',
+};
+
+/**
+ * @dev Define the creator settings of a challenge.
+ */
+export interface CreateChallengePayload {
+  challengeId: string;
+  minDeposit: BN;
+  rewardMintAddress: string;
+  joinDeadline: BN;
+  endTime: BN;
+  maxPlayers?: BN;
+  maxDepositPerPlayer?: BN;
+  maxPrizePool?: BN;
+  startTime?: BN;
+  earlyExitPenaltyBps?: number;
+  milestones?: BN[];
+  judge?: PublicKey;
+  noWinnerPolicy?: Record<string, any>;
+  noWinnerRolloverTarget?: PublicKey;
+  payoutModel?: Record<string, any>;
+  payoutTable?: number[];
+  payoutRemainderPolicy?: Record<string, any>;
+  creatorFeeBps?: number;
+  metadata?: ChallengeMetadataPayload;
+  signer: PublicKey;
+}
+
+/**
+ * @dev Convert the creator settings to the program params.
+ * @param payload
+ */
+const toCreateChallengeParams = (payload: CreateChallengePayload) => ({
+  rewardTokenMintAccount: new PublicKey(payload.rewardMintAddress),
+  id: payload.challengeId,
+  minDeposit: payload.minDeposit,
+  joinDeadline: payload.joinDeadline,
+  endTime: payload.endTime,
+  maxPlayers: payload.maxPlayers || null,
+  maxDepositPerPlayer: payload.maxDepositPerPlayer || null,
+  maxPrizePool: payload.maxPrizePool || null,
+  startTime: payload.startTime || null,
+  earlyExitPenaltyBps: payload.earlyExitPenaltyBps || 0,
+  milestones: payload.milestones || [],
+  judge: payload.judge || null,
+  noWinnerPolicy: payload.noWinnerPolicy || { refundPlayers: {} },
+  noWinnerRolloverTarget: payload.noWinnerRolloverTarget || null,
+  payoutModel: payload.payoutModel || { equalSplit: {} },
+  payoutTable: payload.payoutTable || [],
+  payoutRemainderPolicy: payload.payoutRemainderPolicy || { lastClaimer: {} },
+  creatorFeeBps: payload.creatorFeeBps || 0,
+  metadata: payload.metadata || EMPTY_METADATA,
+});
+
+/**
+ * @dev Convert the creator settings to the update params, the metadata is updated separately.
+ * @param payload
+ */
+const toUpdateChallengeParams = (payload: CreateChallengePayload) => {
+  const { metadata, ...params } = toCreateChallengeParams(payload);
+  return params;
+};
 
 /**
  * @dev `ChallengeInstructionBuilder` helps build the instruction that aligns with the business logic.
@@ -27,25 +111,1053 @@ export class ChallengeInstructionBuilder {
   ) {}
 
   /**
-   * @dev Initialize challenge
+   * @dev Initialize challenge
+   */
+  public async initializeChallengeRegistry(): Promise<
+    TransactionInstruction[]
+  > {
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+
+    /**
+     * @dev return the  token vault creation instruction.
+     */
+    const instruction = await this.program.methods
+      .initialize({
+        allowedMintAccounts: [],
+        allowedAdministrators: [],
+        expiryGracePeriod: new BN(0),
+        disputeWindow: new BN(0),
+        correctionWindow: new BN(0),
+        protocolFeeBps: 0,
+        maxCreatorFeeBps: 0,
+        referralBps: 0,
+        referralFeeSource: { protocolFee: {} },
+      })
+      .accounts({
+        owner: this.program.provider.publicKey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Update challenge registry, the whitelisted mints are kept unless given.
+   */
+  public async updateChallengeRegistry(payload: {
+    allowedAdministrators: PublicKey[];
+    allowedMintAccounts?: ChallengePlatformRegistryState['allowedMintAccounts'];
+    expiryGracePeriod?: BN;
+    disputeWindow?: BN;
+    correctionWindow?: BN;
+    protocolFeeBps?: number;
+    maxCreatorFeeBps?: number;
+    referralBps?: number;
+    referralFeeSource?: Record<string, any>;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const { allowedMintAccounts } =
+      await this.challengeState.getChallengeRegistry();
+
+    /**
+     * @dev return the  token vault creation instruction.
+     */
+    const instruction = await this.program.methods
+      .updateChallengeRegistry({
+        allowedMintAccounts: payload.allowedMintAccounts || allowedMintAccounts,
+        allowedAdministrators: payload.allowedAdministrators,
+        expiryGracePeriod: payload.expiryGracePeriod || new BN(0),
+        disputeWindow: payload.disputeWindow || new BN(0),
+        correctionWindow: payload.correctionWindow || new BN(0),
+        protocolFeeBps: payload.protocolFeeBps || 0,
+        maxCreatorFeeBps: payload.maxCreatorFeeBps || 0,
+        referralBps: payload.referralBps || 0,
+        referralFeeSource: payload.referralFeeSource || { protocolFee: {} },
+      })
+      .accounts({
+        owner: payload.signer,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Create the token vault.
+   * Need an authorized wallet connection from user wallet.
+   * @param payload
+   */
+  public async createTokenVault(payload: {
+    mintTokenAddress: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initializes account, the accounts can be empty when it's not initialized yet.
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [tokenVaultPubkey],
+      accountInfo,
+    } = await this.pdaFinder.getTokenVaultAccount(payload.mintTokenAddress);
+    const {
+      address: [treasuryPubkey],
+    } = await this.pdaFinder.getTreasuryAccount(payload.mintTokenAddress);
+    const mintAccount = new PublicKey(payload.mintTokenAddress);
+
+    /**
+     * @dev The account was initialized, so we don't need this instruction.
+     */
+    if (!!accountInfo) {
+      return [];
+    }
+
+    /**
+     * @dev return the  token vault creation instruction.
+     */
+    const instruction = await this.program.methods
+      .createTokenVault()
+      .accounts({
+        signer: payload.signer,
+        mintAccount,
+        challengeRegistry: challengeRegistryPubkey,
+        challengeTokenVault: tokenVaultPubkey,
+        challengeTreasury: treasuryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Create the treasury of a mint whitelisted before treasuries existed.
+   * @param payload
+   */
+  public async createTreasury(payload: {
+    mintTokenAddress: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initializes account, the accounts can be empty when it's not initialized yet.
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [treasuryPubkey],
+      accountInfo,
+    } = await this.pdaFinder.getTreasuryAccount(payload.mintTokenAddress);
+    const mintAccount = new PublicKey(payload.mintTokenAddress);
+
+    /**
+     * @dev The account was initialized, so we don't need this instruction.
+     */
+    if (!!accountInfo) {
+      return [];
+    }
+
+    /**
+     * @dev return the treasury creation instruction.
+     */
+    const instruction = await this.program.methods
+      .createTreasury()
+      .accounts({
+        signer: payload.signer,
+        mintAccount,
+        challengeRegistry: challengeRegistryPubkey,
+        challengeTreasury: treasuryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Administrator withdraws the fees accrued in the treasury of a mint.
+   * @param payload
+   */
+  public async withdrawFees(payload: {
+    mintTokenAddress: string;
+    amount: BN;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const mintAccount = new PublicKey(payload.mintTokenAddress);
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeTokenVault, challengeTokenVaultBump],
+    } = await this.pdaFinder.getTokenVaultAccount(payload.mintTokenAddress);
+    const {
+      address: [challengeTreasuryPubkey],
+    } = await this.pdaFinder.getTreasuryAccount(payload.mintTokenAddress);
+    const {
+      address: [signerTokenAccount],
+    } = await this.pdaFinder.getTokenAccountOf(mintAccount, payload.signer);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .withdrawFees({
+        challengeTokenVaultBump,
+        amount: payload.amount,
+      })
+      .accounts({
+        signer: payload.signer,
+        mintAccount,
+        challengeRegistry: challengeRegistryPubkey,
+        signerTokenAccount,
+        challengeTokenVault,
+        challengeTreasury: challengeTreasuryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Administrator overrides the protocol fee of a mint, null falls back to the registry fee.
+   * @param payload
+   */
+  public async setMintProtocolFee(payload: {
+    mintTokenAddress: string;
+    protocolFeeBps: number | null;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .setMintProtocolFee({
+        mintAccount: new PublicKey(payload.mintTokenAddress),
+        protocolFeeBps: payload.protocolFeeBps,
+      })
+      .accounts({
+        signer: payload.signer,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Create a challenge with the pre-defined id and other params.
+   * @param payload
+   */
+  public async createChallenge(
+    payload: CreateChallengePayload,
+  ): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [challengeMetadataPubkey],
+    } = await this.pdaFinder.getChallengeMetadataAccount(payload.challengeId);
+    const {
+      address: [challengeSeriesPubkey],
+    } = await this.pdaFinder.getRoundSeriesAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      // @ts-ignore
+      .createChallenge(toCreateChallengeParams(payload))
+      .accounts({
+        challengeOwner: payload.signer,
+        challenge: challengePubkey,
+        challengeMetadata: challengeMetadataPubkey,
+        challengeRegistry: challengeRegistryPubkey,
+        challengeSeries: challengeSeriesPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Create a challenge from a template, the settings must be within the template.
+   * @param payload
+   */
+  public async createChallengeFromTemplate(
+    payload: CreateChallengePayload & { templateId: string },
+  ): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeTemplatePubkey],
+    } = await this.pdaFinder.getChallengeTemplateAccount(payload.templateId);
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [challengeMetadataPubkey],
+    } = await this.pdaFinder.getChallengeMetadataAccount(payload.challengeId);
+    const {
+      address: [challengeSeriesPubkey],
+    } = await this.pdaFinder.getRoundSeriesAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .createChallengeFromTemplate({
+        templateId: payload.templateId,
+        // @ts-ignore
+        challenge: toCreateChallengeParams(payload),
+      })
+      .accounts({
+        challengeOwner: payload.signer,
+        challengeTemplate: challengeTemplatePubkey,
+        challenge: challengePubkey,
+        challengeMetadata: challengeMetadataPubkey,
+        challengeRegistry: challengeRegistryPubkey,
+        challengeSeries: challengeSeriesPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Build the challenge template instruction.
+   * @param payload
+   */
+  private async buildChallengeTemplateInstruction(payload: {
+    templateId: string;
+    isEnabled: boolean;
+    rewardMintAddress: string;
+    minDeposit: BN;
+    maxDeposit: BN;
+    minDuration: BN;
+    maxDuration: BN;
+    payoutModel?: Record<string, any>;
+    feeBps?: number;
+    isUpdating: boolean;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeTemplatePubkey],
+    } = await this.pdaFinder.getChallengeTemplateAccount(payload.templateId);
+
+    const params = {
+      id: payload.templateId,
+      isEnabled: payload.isEnabled,
+      rewardTokenMintAccount: new PublicKey(payload.rewardMintAddress),
+      minDeposit: payload.minDeposit,
+      maxDeposit: payload.maxDeposit,
+      minDuration: payload.minDuration,
+      maxDuration: payload.maxDuration,
+      payoutModel: payload.payoutModel || { equalSplit: {} },
+      feeBps: payload.feeBps || 0,
+    };
+    const accounts = {
+      signer: payload.signer,
+      challengeTemplate: challengeTemplatePubkey,
+      challengeRegistry: challengeRegistryPubkey,
+    };
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = payload.isUpdating
+      ? await this.program.methods
+          // @ts-ignore
+          .updateChallengeTemplate(params)
+          .accounts(accounts)
+          .instruction()
+      : await this.program.methods
+          // @ts-ignore
+          .createChallengeTemplate(params)
+          .accounts(accounts)
+          .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Create a challenge template, administrators only.
+   * @param payload
+   */
+  public async createChallengeTemplate(payload: {
+    templateId: string;
+    rewardMintAddress: string;
+    minDeposit: BN;
+    maxDeposit: BN;
+    minDuration: BN;
+    maxDuration: BN;
+    payoutModel?: Record<string, any>;
+    feeBps?: number;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.buildChallengeTemplateInstruction({
+      ...payload,
+      isEnabled: true,
+      isUpdating: false,
+    });
+  }
+
+  /**
+   * @dev Update or disable a challenge template, administrators only.
+   * @param payload
+   */
+  public async updateChallengeTemplate(payload: {
+    templateId: string;
+    isEnabled: boolean;
+    rewardMintAddress: string;
+    minDeposit: BN;
+    maxDeposit: BN;
+    minDuration: BN;
+    maxDuration: BN;
+    payoutModel?: Record<string, any>;
+    feeBps?: number;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.buildChallengeTemplateInstruction({
+      ...payload,
+      isUpdating: true,
+    });
+  }
+
+  /**
+   * @dev Create a recurring challenge series.
+   * @param payload
+   */
+  public async createChallengeSeries(payload: {
+    seriesId: string;
+    minDeposit: BN;
+    rewardMintAddress: string;
+    joinDuration: BN;
+    duration: BN;
+    rolloverEnabled: boolean;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeSeriesPubkey],
+    } = await this.pdaFinder.getChallengeSeriesAccount(payload.seriesId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .createChallengeSeries({
+        id: payload.seriesId,
+        minDeposit: payload.minDeposit,
+        rewardTokenMintAccount: new PublicKey(payload.rewardMintAddress),
+        joinDuration: payload.joinDuration,
+        duration: payload.duration,
+        rolloverEnabled: payload.rolloverEnabled,
+      })
+      .accounts({
+        seriesOwner: payload.signer,
+        challengeSeries: challengeSeriesPubkey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Spawn the next round of a series.
+   * @param payload
+   */
+  public async spawnSeriesRound(payload: {
+    seriesId: string;
+    metadata?: ChallengeMetadataPayload;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Derive the next round id from the series state.
+     */
+    const series = await this.challengeState.getChallengeSeries(
+      payload.seriesId,
+    );
+    const challengeId = `${payload.seriesId}-${series.round.toNumber() + 1}`;
+
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeSeriesPubkey],
+    } = await this.pdaFinder.getChallengeSeriesAccount(payload.seriesId);
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(challengeId);
+    const {
+      address: [challengeMetadataPubkey],
+    } = await this.pdaFinder.getChallengeMetadataAccount(challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .spawnSeriesRound({
+        seriesId: payload.seriesId,
+        challengeId,
+        metadata: payload.metadata || EMPTY_METADATA,
+      })
+      .accounts({
+        seriesOwner: payload.signer,
+        challengeSeries: challengeSeriesPubkey,
+        challenge: challengePubkey,
+        challengeMetadata: challengeMetadataPubkey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Update challenge params before anyone joins.
+   * @param payload
+   */
+  public async updateChallenge(
+    payload: Omit<CreateChallengePayload, 'metadata'>,
+  ): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      // @ts-ignore
+      .updateChallenge(toUpdateChallengeParams(payload))
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Update a templated challenge before anyone joins, the settings must stay within the template.
+   * @param payload
+   */
+  public async updateChallengeFromTemplate(
+    payload: Omit<CreateChallengePayload, 'metadata'> & { templateId: string },
+  ): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeTemplatePubkey],
+    } = await this.pdaFinder.getChallengeTemplateAccount(payload.templateId);
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .updateChallengeFromTemplate({
+        templateId: payload.templateId,
+        // @ts-ignore
+        challenge: toUpdateChallengeParams(payload),
+      })
+      .accounts({
+        signer: payload.signer,
+        challengeTemplate: challengeTemplatePubkey,
+        challenge: challengePubkey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Update challenge metadata before anyone joins.
+   * @param payload
+   */
+  public async updateChallengeMetadata(payload: {
+    challengeId: string;
+    metadata: ChallengeMetadataPayload;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [challengeMetadataPubkey],
+    } = await this.pdaFinder.getChallengeMetadataAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .updateChallengeMetadata({
+        id: payload.challengeId,
+        metadata: payload.metadata,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        challengeMetadata: challengeMetadataPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Cancel challenge.
+   */
+  public async cancelChallenge(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .cancelChallenge({
+        id: payload.challengeId,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Move a challenge into its scheduled phase.
+   */
+  public async transitionChallengeStatus(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .transitionChallengeStatus({
+        challengeId: payload.challengeId,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Expire an abandoned challenge.
+   */
+  public async expireChallenge(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .expireChallenge({
+        id: payload.challengeId,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Administrator cancels a challenge in an emergency.
+   */
+  public async forceCancelChallenge(payload: {
+    challengeId: string;
+    reasonCode: number;
+    reasonUri?: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .forceCancelChallenge({
+        challengeId: payload.challengeId,
+        reasonCode: payload.reasonCode,
+        reasonUri: payload.reasonUri || '',
+      })
+      .accounts({
+        signer: payload.signer,
+        challengeRegistry: challengeRegistryPubkey,
+        challenge: challengePubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Close a settled challenge, the rent goes back to the challenge owner.
+   */
+  public async closeChallenge(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [challengeMetadataPubkey],
+    } = await this.pdaFinder.getChallengeMetadataAccount(payload.challengeId);
+    const challenge = await this.challengeState.getChallenge(
+      payload.challengeId,
+    );
+    const {
+      address: [challengeTreasuryPubkey],
+    } = await this.pdaFinder.getTreasuryAccount(
+      challenge.rewardTokenMintAccount.toBase58(),
+    );
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .closeChallenge({
+        challengeId: payload.challengeId,
+      })
+      .accounts({
+        signer: payload.signer,
+        challengeRegistry: challengeRegistryPubkey,
+        challengeOwner: challenge.owner,
+        challenge: challengePubkey,
+        challengeMetadata: challengeMetadataPubkey,
+        challengeTreasury: challengeTreasuryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Close the dispute of a settled challenge.
+   */
+  public async closeDispute(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [disputePubkey],
+    } = await this.pdaFinder.getDisputeAccount(payload.challengeId);
+    const dispute = await this.challengeState.getDispute(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .closeDispute({
+        challengeId: payload.challengeId,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        disputer: dispute.disputer,
+        dispute: disputePubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Close the proof submission of a player in a settled challenge.
+   */
+  public async closeSubmission(payload: {
+    challengeId: string;
+    player: PublicKey;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [submissionPubkey],
+    } = await this.pdaFinder.getSubmissionAccount(
+      payload.challengeId,
+      payload.player,
+    );
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .closeSubmission({
+        challengeId: payload.challengeId,
+        player: payload.player,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        player: payload.player,
+        submission: submissionPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Submit winner list.
+   */
+  public async submitWinnerList(payload: {
+    challengeId: string;
+    winnerList: PublicKey[];
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengeRegistryPubkey],
+    } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .submitWinnerList({
+        id: payload.challengeId,
+        winnerList: payload.winnerList,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        challengeRegistry: challengeRegistryPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Record a milestone check-in of a player.
+   */
+  public async recordCheckIn(payload: {
+    challengeId: string;
+    player: PublicKey;
+    milestoneIndex: number;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .recordCheckIn({
+        challengeId: payload.challengeId,
+        player: payload.player,
+        milestoneIndex: payload.milestoneIndex,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Settle a milestone challenge with its survivors.
    */
-  public async initializeChallengeRegistry(): Promise<
-    TransactionInstruction[]
-  > {
+  public async settleMilestoneChallenge(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
     const {
       address: [challengeRegistryPubkey],
     } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
 
     /**
-     * @dev return the  token vault creation instruction.
+     * @dev Build the instruction.
      */
     const instruction = await this.program.methods
-      .initialize({
-        allowedMintAccounts: [],
-        allowedAdministrators: [],
+      .settleMilestoneChallenge({
+        challengeId: payload.challengeId,
       })
       .accounts({
-        owner: this.program.provider.publicKey,
+        signer: payload.signer,
+        challenge: challengePubkey,
         challengeRegistry: challengeRegistryPubkey,
       })
       .instruction();
@@ -57,26 +1169,34 @@ export class ChallengeInstructionBuilder {
   }
 
   /**
-   * @dev Initialize challenge
+   * @dev Amend the winner list before the first claim.
    */
-  public async updateChallengeRegistry(payload: {
-    allowedAdministrators: PublicKey[];
+  public async amendWinnerList(payload: {
+    challengeId: string;
+    winnerList: PublicKey[];
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
     const {
       address: [challengeRegistryPubkey],
     } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
 
     /**
-     * @dev return the  token vault creation instruction.
+     * @dev Build the instruction.
      */
     const instruction = await this.program.methods
-      .updateChallengeRegistry({
-        allowedMintAccounts: [],
-        allowedAdministrators: payload.allowedAdministrators,
+      .amendWinnerList({
+        challengeId: payload.challengeId,
+        winnerList: payload.winnerList,
       })
       .accounts({
-        owner: payload.signer,
+        signer: payload.signer,
+        challenge: challengePubkey,
         challengeRegistry: challengeRegistryPubkey,
       })
       .instruction();
@@ -88,43 +1208,35 @@ export class ChallengeInstructionBuilder {
   }
 
   /**
-   * @dev Create the token vault.
-   * Need an authorized wallet connection from user wallet.
-   * @param payload
+   * @dev Dispute the submitted winner list.
    */
-  public async createTokenVault(payload: {
-    mintTokenAddress: string;
+  public async openDispute(payload: {
+    challengeId: string;
+    reasonUri: string;
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
     /**
-     * @dev Initializes account, the accounts can be empty when it's not initialized yet.
+     * @dev Initialize the account addresses
      */
     const {
-      address: [challengeRegistryPubkey],
-    } = await this.pdaFinder.getChallengeRegistryAccount();
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
     const {
-      address: [tokenVaultPubkey],
-      accountInfo,
-    } = await this.pdaFinder.getTokenVaultAccount(payload.mintTokenAddress);
-    const mintAccount = new PublicKey(payload.mintTokenAddress);
-
-    /**
-     * @dev The account was initialized, so we don't need this instruction.
-     */
-    if (!!accountInfo) {
-      return [];
-    }
+      address: [disputePubkey],
+    } = await this.pdaFinder.getDisputeAccount(payload.challengeId);
 
     /**
-     * @dev return the  token vault creation instruction.
+     * @dev Build the instruction.
      */
     const instruction = await this.program.methods
-      .createTokenVault()
+      .openDispute({
+        challengeId: payload.challengeId,
+        reasonUri: payload.reasonUri,
+      })
       .accounts({
         signer: payload.signer,
-        mintAccount,
-        challengeRegistry: challengeRegistryPubkey,
-        challengeTokenVault: tokenVaultPubkey,
+        challenge: challengePubkey,
+        dispute: disputePubkey,
       })
       .instruction();
 
@@ -135,13 +1247,13 @@ export class ChallengeInstructionBuilder {
   }
 
   /**
-   * @dev Create a challenge with the pre-defined id and other params.
+   * @dev Resolve the dispute.
    * @param payload
    */
-  public async createChallenge(payload: {
+  private async resolveDispute(payload: {
     challengeId: string;
-    minDeposit: BN;
-    rewardMintAddress: string;
+    actionType: Record<string, any>;
+    winnerList: PublicKey[];
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
     /**
@@ -150,25 +1262,28 @@ export class ChallengeInstructionBuilder {
     const {
       address: [challengeRegistryPubkey],
     } = await this.pdaFinder.getChallengeRegistryAccount();
-
     const {
       address: [challengePubkey],
     } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
-    const mintAccount = new PublicKey(payload.rewardMintAddress);
+    const {
+      address: [disputePubkey],
+    } = await this.pdaFinder.getDisputeAccount(payload.challengeId);
 
     /**
      * @dev Build the instruction.
      */
     const instruction = await this.program.methods
-      .createChallenge({
-        rewardTokenMintAccount: mintAccount,
-        id: payload.challengeId,
-        minDeposit: payload.minDeposit,
+      // @ts-ignore
+      .resolveDispute({
+        challengeId: payload.challengeId,
+        actionType: payload.actionType,
+        winnerList: payload.winnerList,
       })
       .accounts({
-        challengeOwner: payload.signer,
+        signer: payload.signer,
         challenge: challengePubkey,
         challengeRegistry: challengeRegistryPubkey,
+        dispute: disputePubkey,
       })
       .instruction();
 
@@ -179,26 +1294,68 @@ export class ChallengeInstructionBuilder {
   }
 
   /**
-   * @dev Cancel challenge.
+   * @dev Keep the disputed winner list.
+   * @param payload
    */
-  public async cancelChallenge(payload: {
+  public async upholdWinnerList(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.resolveDispute({
+      challengeId: payload.challengeId,
+      actionType: { upholdWinnerList: {} },
+      winnerList: [],
+      signer: payload.signer,
+    });
+  }
+
+  /**
+   * @dev Replace the disputed winner list.
+   * @param payload
+   */
+  public async replaceWinnerList(payload: {
+    challengeId: string;
+    winnerList: PublicKey[];
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.resolveDispute({
+      challengeId: payload.challengeId,
+      actionType: { replaceWinnerList: {} },
+      winnerList: payload.winnerList,
+      signer: payload.signer,
+    });
+  }
+
+  /**
+   * @dev Apply the refund or treasury policy of a challenge without winners.
+   */
+  public async applyNoWinnerPolicy(payload: {
     challengeId: string;
+    rewardMintAddress: string;
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
     const {
       address: [challengePubkey],
     } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [treasuryPubkey],
+    } = await this.pdaFinder.getTreasuryAccount(payload.rewardMintAddress);
+    const { noWinnerRolloverTarget } = await this.challengeState.getChallenge(
+      payload.challengeId,
+    );
 
     /**
      * @dev Build the instruction.
      */
     const instruction = await this.program.methods
-      .cancelChallenge({
-        id: payload.challengeId,
+      .applyNoWinnerPolicy({
+        challengeId: payload.challengeId,
       })
       .accounts({
         signer: payload.signer,
         challenge: challengePubkey,
+        challengeTreasury: treasuryPubkey,
+        rolloverTarget: noWinnerRolloverTarget || challengePubkey,
       })
       .instruction();
 
@@ -209,11 +1366,93 @@ export class ChallengeInstructionBuilder {
   }
 
   /**
-   * @dev Submit winner list.
+   * @dev Roll the prize pool of a challenge without winners over into the designated challenge.
    */
-  public async submitWinnerList(payload: {
+  public async applyNoWinnerRollover(payload: {
+    sourceChallengeId: string;
+    targetChallengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    const {
+      address: [sourceChallengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.sourceChallengeId);
+    const {
+      address: [targetChallengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.targetChallengeId);
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .applyNoWinnerRollover({
+        sourceChallengeId: payload.sourceChallengeId,
+        targetChallengeId: payload.targetChallengeId,
+      })
+      .accounts({
+        signer: payload.signer,
+        sourceChallenge: sourceChallengePubkey,
+        targetChallenge: targetChallengePubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Submit the proof of a player.
+   */
+  public async submitProof(payload: {
     challengeId: string;
-    winnerList: PublicKey[];
+    evidenceUri: string;
+    contentHash: number[];
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    /**
+     * @dev Initialize the account addresses
+     */
+    const {
+      address: [challengePubkey],
+    } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [submissionPubkey],
+    } = await this.pdaFinder.getSubmissionAccount(
+      payload.challengeId,
+      payload.signer,
+    );
+
+    /**
+     * @dev Build the instruction.
+     */
+    const instruction = await this.program.methods
+      .submitProof({
+        challengeId: payload.challengeId,
+        evidenceUri: payload.evidenceUri,
+        contentHash: payload.contentHash,
+      })
+      .accounts({
+        signer: payload.signer,
+        challenge: challengePubkey,
+        submission: submissionPubkey,
+      })
+      .instruction();
+
+    /**
+     * @dev Return the instruction
+     */
+    return [instruction];
+  }
+
+  /**
+   * @dev Review the proof of a player.
+   * @param payload
+   */
+  private async reviewProof(payload: {
+    challengeId: string;
+    player: PublicKey;
+    actionType: Record<string, any>;
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
     /**
@@ -225,19 +1464,28 @@ export class ChallengeInstructionBuilder {
     const {
       address: [challengePubkey],
     } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
+    const {
+      address: [submissionPubkey],
+    } = await this.pdaFinder.getSubmissionAccount(
+      payload.challengeId,
+      payload.player,
+    );
 
     /**
      * @dev Build the instruction.
      */
     const instruction = await this.program.methods
-      .submitWinnerList({
-        id: payload.challengeId,
-        winnerList: payload.winnerList,
+      // @ts-ignore
+      .reviewProof({
+        challengeId: payload.challengeId,
+        player: payload.player,
+        actionType: payload.actionType,
       })
       .accounts({
         signer: payload.signer,
         challenge: challengePubkey,
         challengeRegistry: challengeRegistryPubkey,
+        submission: submissionPubkey,
       })
       .instruction();
 
@@ -247,6 +1495,40 @@ export class ChallengeInstructionBuilder {
     return [instruction];
   }
 
+  /**
+   * @dev Accept the proof of a player.
+   * @param payload
+   */
+  public async acceptProof(payload: {
+    challengeId: string;
+    player: PublicKey;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.reviewProof({
+      challengeId: payload.challengeId,
+      player: payload.player,
+      actionType: { acceptProof: {} },
+      signer: payload.signer,
+    });
+  }
+
+  /**
+   * @dev Reject the proof of a player.
+   * @param payload
+   */
+  public async rejectProof(payload: {
+    challengeId: string;
+    player: PublicKey;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.reviewProof({
+      challengeId: payload.challengeId,
+      player: payload.player,
+      actionType: { rejectProof: {} },
+      signer: payload.signer,
+    });
+  }
+
   /**
    * @dev build instruction
    * @param payload
@@ -259,7 +1541,8 @@ export class ChallengeInstructionBuilder {
     /**
      * @dev Initialize account addresses
      */
-    const [mintAccount] = await this.challengeState.getWhitelistedToken();
+    const { rewardTokenMintAccount: mintAccount } =
+      await this.challengeState.getChallenge(payload.challengeId);
     const {
       address: [challengePubkey],
     } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
@@ -272,6 +1555,9 @@ export class ChallengeInstructionBuilder {
     const {
       address: [challengeRegistryPubkey],
     } = await this.pdaFinder.getChallengeRegistryAccount();
+    const {
+      address: [challengeTreasuryPubkey],
+    } = await this.pdaFinder.getTreasuryAccount(mintAccount.toBase58());
 
     /**
      * @dev Build the instruction.
@@ -289,6 +1575,7 @@ export class ChallengeInstructionBuilder {
         challengeTokenVault,
         signerTokenAccount,
         challengeRegistry: challengeRegistryPubkey,
+        challengeTreasury: challengeTreasuryPubkey,
         mintAccount,
       })
       .instruction();
@@ -307,13 +1594,15 @@ export class ChallengeInstructionBuilder {
     challengeId: string;
     amount: BN;
     actionType: Record<string, any>;
+    referrer?: PublicKey;
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
     /**
      * @dev Initialize account addresses
      *
      */
-    const [mintAccount] = await this.challengeState.getWhitelistedToken();
+    const { rewardTokenMintAccount: mintAccount } =
+      await this.challengeState.getChallenge(payload.challengeId);
     const {
       address: [challengePubkey],
     } = await this.pdaFinder.getChallengeAccount(payload.challengeId);
@@ -334,6 +1623,7 @@ export class ChallengeInstructionBuilder {
         challengeId: payload.challengeId,
         amount: new BN(payload.amount),
         challengeTokenVaultBump,
+        referrer: payload.referrer || null,
       })
       .accounts({
         signer: payload.signer,
@@ -357,12 +1647,14 @@ export class ChallengeInstructionBuilder {
   public async joinChallenge(payload: {
     challengeId: string;
     amount: BN;
+    referrer?: PublicKey;
     signer: PublicKey;
   }): Promise<TransactionInstruction[]> {
     return this.transferAssetsToVault({
       challengeId: payload.challengeId,
       actionType: { joinChallenge: {} },
       amount: payload.amount,
+      referrer: payload.referrer,
       signer: payload.signer,
     });
   }
@@ -428,4 +1720,64 @@ export class ChallengeInstructionBuilder {
       signer: payload.signer,
     });
   }
+
+  /**
+   * @dev Donor gets the donation back from a canceled challenge.
+   * @param payload
+   */
+  public async refundDonation(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.transferAssetsFromVault({
+      challengeId: payload.challengeId,
+      actionType: { refundingDonation: {} },
+      signer: payload.signer,
+    });
+  }
+
+  /**
+   * @dev Challenge owner claims the creator fee once the result is final.
+   * @param payload
+   */
+  public async claimCreatorFee(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.transferAssetsFromVault({
+      challengeId: payload.challengeId,
+      actionType: { claimingCreatorFee: {} },
+      signer: payload.signer,
+    });
+  }
+
+  /**
+   * @dev Referrer claims the referral reward once the result is final.
+   * @param payload
+   */
+  public async claimReferralReward(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.transferAssetsFromVault({
+      challengeId: payload.challengeId,
+      actionType: { claimingReferralReward: {} },
+      signer: payload.signer,
+    });
+  }
+
+  /**
+   * @dev Leave a challenge before it starts.
+   * @param payload
+   */
+  public async leaveChallenge(payload: {
+    challengeId: string;
+    signer: PublicKey;
+  }): Promise<TransactionInstruction[]> {
+    return this.transferAssetsFromVault({
+      challengeId: payload.challengeId,
+      actionType: { leavingChallenge: {} },
+      signer: payload.signer,
+    });
+  }
 }
@@ -6,8 +6,14 @@ import { Wallet } from '@project-serum/anchor';
  */
 import { ProgramBuilder } from './program.builder';
 import {
+  ChallengeMetadataState,
   ChallengePlatformRegistryState,
+  ChallengeSeriesState,
+  ChallengeDisputeState,
   ChallengeState,
+  ChallengeSubmissionState,
+  ChallengeTemplateState,
+  ChallengeTreasuryState,
 } from './challenge.idl';
 import { PDAFinder } from './program.finder';
 
@@ -41,6 +47,120 @@ export class ChallengeProgramState {
     return program.account.challenge.fetch(challengeAddress.address[0]);
   }
 
+  /**
+   * @dev Get challenge metadata state.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param challengeId
+   */
+  public async getChallengeMetadata(
+    challengeId: string,
+  ): Promise<ChallengeMetadataState> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const challengeMetadataAddress =
+      await this.programFinder.getChallengeMetadataAccount(challengeId);
+    return program.account.challengeMetadata.fetch(
+      challengeMetadataAddress.address[0],
+    );
+  }
+
+  /**
+   * @dev Get challenge dispute state.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param challengeId
+   */
+  public async getDispute(challengeId: string): Promise<ChallengeDisputeState> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const disputeAddress = await this.programFinder.getDisputeAccount(
+      challengeId,
+    );
+    return program.account.challengeDispute.fetch(disputeAddress.address[0]);
+  }
+
+  /**
+   * @dev Get player submission state.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param challengeId
+   * @param player
+   */
+  public async getSubmission(
+    challengeId: string,
+    player: PublicKey,
+  ): Promise<ChallengeSubmissionState> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const submissionAddress = await this.programFinder.getSubmissionAccount(
+      challengeId,
+      player,
+    );
+    return program.account.challengeSubmission.fetch(
+      submissionAddress.address[0],
+    );
+  }
+
+  /**
+   * @dev Get challenge series state.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param seriesId
+   */
+  public async getChallengeSeries(
+    seriesId: string,
+  ): Promise<ChallengeSeriesState> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const challengeSeriesAddress =
+      await this.programFinder.getChallengeSeriesAccount(seriesId);
+    return program.account.challengeSeries.fetch(
+      challengeSeriesAddress.address[0],
+    );
+  }
+
+  /**
+   * @dev Get challenge template state.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param templateId
+   */
+  public async getChallengeTemplate(
+    templateId: string,
+  ): Promise<ChallengeTemplateState> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const challengeTemplateAddress =
+      await this.programFinder.getChallengeTemplateAccount(templateId);
+    return program.account.challengeTemplate.fetch(
+      challengeTemplateAddress.address[0],
+    );
+  }
+
+  /**
+   * @dev Get treasury state of a mint.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param mintAccount
+   */
+  public async getTreasury(
+    mintAccount: string,
+  ): Promise<ChallengeTreasuryState> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const treasuryAddress = await this.programFinder.getTreasuryAccount(
+      mintAccount,
+    );
+    return program.account.challengeTreasury.fetch(treasuryAddress.address[0]);
+  }
+
   /**
    * @dev Get challenge registry state.
    * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
@@ -52,6 +52,145 @@ export class PDAFinder {
     };
   }
 
+  /**
+   * @dev Find challenge metadata PDA.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param challengeId
+   */
+  public async getChallengeMetadataAccount(
+    challengeId: string,
+  ): Promise<PDAFinderResponse> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const {
+      address: [challengePubkey],
+    } = await this.getChallengeAccount(challengeId);
+
+    const [pubkey, bump] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode('SEED::CHALLENGE::METADATA'),
+        challengePubkey.toBytes(),
+      ],
+      program.programId,
+    );
+
+    return {
+      accountInfo: await this.programBuilder
+        .getConnection()
+        .getAccountInfo(pubkey),
+      address: [pubkey, bump],
+    };
+  }
+
+  /**
+   * @dev Find challenge dispute PDA.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param challengeId
+   */
+  public async getDisputeAccount(
+    challengeId: string,
+  ): Promise<PDAFinderResponse> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const {
+      address: [challengePubkey],
+    } = await this.getChallengeAccount(challengeId);
+
+    const [pubkey, bump] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode('SEED::CHALLENGE::DISPUTE'),
+        challengePubkey.toBytes(),
+      ],
+      program.programId,
+    );
+
+    return {
+      accountInfo: await this.programBuilder
+        .getConnection()
+        .getAccountInfo(pubkey),
+      address: [pubkey, bump],
+    };
+  }
+
+  /**
+   * @dev Find player submission PDA.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param challengeId
+   * @param player
+   */
+  public async getSubmissionAccount(
+    challengeId: string,
+    player: PublicKey,
+  ): Promise<PDAFinderResponse> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const {
+      address: [challengePubkey],
+    } = await this.getChallengeAccount(challengeId);
+
+    const [pubkey, bump] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode('SEED::CHALLENGE::SUBMISSION'),
+        challengePubkey.toBytes(),
+        player.toBytes(),
+      ],
+      program.programId,
+    );
+
+    return {
+      accountInfo: await this.programBuilder
+        .getConnection()
+        .getAccountInfo(pubkey),
+      address: [pubkey, bump],
+    };
+  }
+
+  /**
+   * @dev Find challenge series PDA.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param seriesId
+   */
+  public async getChallengeSeriesAccount(
+    seriesId: string,
+  ): Promise<PDAFinderResponse> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const [pubkey, bump] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode('SEED::CHALLENGE::SERIES'),
+        anchor.utils.bytes.utf8.encode(seriesId),
+      ],
+      program.programId,
+    );
+
+    return {
+      accountInfo: await this.programBuilder
+        .getConnection()
+        .getAccountInfo(pubkey),
+      address: [pubkey, bump],
+    };
+  }
+
+  /**
+   * @dev Find the series PDA a challenge id would be a round of.
+   * Ids without a round suffix are looked up as a series id, the program ignores them.
+   * @param challengeId
+   */
+  public async getRoundSeriesAccount(
+    challengeId: string,
+  ): Promise<PDAFinderResponse> {
+    const seriesId = challengeId.match(/^(.+)-\d+$/)?.[1] || challengeId;
+    return this.getChallengeSeriesAccount(seriesId);
+  }
+
   /**
    * @dev Find challenge registry pda.
    * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
@@ -103,6 +242,62 @@ export class PDAFinder {
     };
   }
 
+  /**
+   * @dev Find challenge template PDA.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param templateId
+   */
+  public async getChallengeTemplateAccount(
+    templateId: string,
+  ): Promise<PDAFinderResponse> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const [pubkey, bump] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode('SEED::CHALLENGE::TEMPLATE'),
+        anchor.utils.bytes.utf8.encode(templateId),
+      ],
+      program.programId,
+    );
+
+    return {
+      accountInfo: await this.programBuilder
+        .getConnection()
+        .getAccountInfo(pubkey),
+      address: [pubkey, bump],
+    };
+  }
+
+  /**
+   * @dev Find treasury pda of a mint.
+   * We use a read-only public program authority to avoid unexpected behaviors related to user wallet connection.
+   * @param mintAccount
+   */
+  public async getTreasuryAccount(
+    mintAccount: string,
+  ): Promise<PDAFinderResponse> {
+    const program = await this.programBuilder.getProgram(
+      new Wallet(Keypair.generate()),
+    );
+
+    const [pubkey, bump] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode('SEED::CHALLENGE::TREASURY'),
+        new PublicKey(mintAccount).toBytes(),
+      ],
+      program.programId,
+    );
+
+    return {
+      accountInfo: await this.programBuilder
+        .getConnection()
+        .getAccountInfo(pubkey),
+      address: [pubkey, bump],
+    };
+  }
+
   /**
    * @dev Get associated token account of a public key.
    * @param mint
@@ -0,0 +1,77 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct AmendWinnerListParams {
+    pub challenge_id: String,
+    pub winner_list: Vec<Pubkey>,
+}
+
+// Define the context, passed in parameters when trigger from administrators or challenge owner.
+#[derive(Accounts)]
+#[instruction(params: AmendWinnerListParams)]
+pub struct AmendWinnerListContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AmendWinnerListContext<'info> {
+    pub fn execute(&mut self, params: AmendWinnerListParams) -> Result<()> {
+        let challenge = &mut self.challenge;
+        let is_administrator = self.challenge_registry.is_administrator(self.signer.key().clone());
+
+        // require administrator permission or challenge owner
+        if !(is_administrator || challenge.is_challenge_owner(self.signer.key().clone())) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // milestone challenges are settled by check-ins
+        if challenge.is_milestone_challenge() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // the winner list can only be amended before the first claim within the correction window
+        if !challenge.is_winner_list_amendable_for(self.challenge_registry.correction_window, is_administrator) {
+            return Err(ChallengeError::WinnerListCannotBeAmended.into());
+        }
+
+        // now we replace the winner list
+        let previous_winner_list = challenge.get_winner_list().unwrap();
+        challenge.set_winner_list(params.winner_list.clone()).unwrap();
+
+        // the amended winner list can be disputed for a full window again
+        if challenge.status == ChallengeStatus::PendingResult {
+            let clock = Clock::get().unwrap();
+            challenge.dispute_deadline = clock.unix_timestamp + self.challenge_registry.dispute_window;
+        }
+
+        // emit event
+        challenge_emit!(
+            WinnerListAmended {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                id: challenge.id.clone(),
+                previous_winner_list,
+                winner_list: challenge.get_winner_list().unwrap(),
+            }
+        );
+
+        return Ok(());
+    }
+}
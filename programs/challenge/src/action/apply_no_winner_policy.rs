@@ -0,0 +1,95 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ApplyNoWinnerPolicyParams {
+    pub challenge_id: String,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: ApplyNoWinnerPolicyParams)]
+pub struct ApplyNoWinnerPolicyContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, challenge.reward_token_mint_account.as_ref()],
+        bump = challenge_treasury.bump,
+    )]
+    pub challenge_treasury: Account<'info, ChallengeTreasury>,
+
+    /// CHECK: the designated rollover challenge, only read to know whether it can still receive the prize pool
+    pub rollover_target: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApplyNoWinnerPolicyContext<'info> {
+    pub fn execute(&mut self, _params: ApplyNoWinnerPolicyParams) -> Result<()> {
+        let challenge = &mut self.challenge;
+        let challenge_key = challenge.key().clone();
+
+        // the result must be final without winners
+        if !challenge.is_no_winner_policy_applicable() {
+            return Err(ChallengeError::NoWinnerPolicyIsNotAvailable.into());
+        }
+
+        // rolling over requires the designated challenge, the players are refunded when it cannot receive the prize pool
+        let mut policy = challenge.no_winner_policy;
+        if policy == NoWinnerPolicy::RollOver {
+            if challenge.no_winner_rollover_target != Some(self.rollover_target.key()) {
+                return Err(ChallengeError::NoWinnerPolicyIsNotAvailable.into());
+            }
+
+            let rollover_target_info = self.rollover_target.to_account_info();
+            if let Ok(rollover_target) = Account::<Challenge>::try_from(&rollover_target_info) {
+                if challenge.is_challenge_open_for_no_winner_rollover_into(&rollover_target, rollover_target.key()) {
+                    return Err(ChallengeError::NoWinnerPolicyIsNotAvailable.into());
+                }
+            }
+
+            policy = NoWinnerPolicy::RefundPlayers;
+        }
+
+        let mut amount = challenge.prize_pool;
+
+        // players can withdraw their deposits once the challenge is canceled
+        if policy == NoWinnerPolicy::RefundPlayers {
+            amount = challenge.players.iter()
+                .map(|player| player.total_deposit)
+                .sum();
+            challenge.transition_to(ChallengeStatus::Canceled, self.signer.key().clone(), challenge_key).unwrap();
+        }
+
+        // the prize pool is now owned by the platform
+        if policy == NoWinnerPolicy::SendToTreasury {
+            self.challenge_treasury.balance += amount;
+            challenge.prize_pool = 0;
+            challenge.donate_pool = 0;
+            challenge.rollover_pool = 0;
+            challenge.transition_to(ChallengeStatus::Claimed, self.signer.key().clone(), challenge_key).unwrap();
+        }
+
+        // emit event
+        challenge_emit!(
+            NoWinnerPolicyApplied {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                policy,
+                amount,
+            }
+        );
+
+        return Ok(());
+    }
+}
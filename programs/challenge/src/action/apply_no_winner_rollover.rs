@@ -0,0 +1,77 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ApplyNoWinnerRolloverParams {
+    pub source_challenge_id: String,
+    pub target_challenge_id: String,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: ApplyNoWinnerRolloverParams)]
+pub struct ApplyNoWinnerRolloverContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.source_challenge_id.as_bytes().as_ref()],
+        bump = source_challenge.bump,
+    )]
+    pub source_challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.target_challenge_id.as_bytes().as_ref()],
+        bump = target_challenge.bump,
+    )]
+    pub target_challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApplyNoWinnerRolloverContext<'info> {
+    pub fn execute(&mut self, _params: ApplyNoWinnerRolloverParams) -> Result<()> {
+        // the source must be final without winners and the designated challenge must be open
+        if !self.source_challenge.is_challenge_open_for_no_winner_rollover_into(
+            &self.target_challenge,
+            self.target_challenge.key(),
+        ) {
+            return Err(ChallengeError::NoWinnerPolicyIsNotAvailable.into());
+        }
+
+        // the rolled funds are not refundable to the target players nor to any donor
+        let amount = self.source_challenge.prize_pool;
+        self.source_challenge.prize_pool = 0;
+        self.source_challenge.donate_pool = 0;
+        self.source_challenge.rollover_pool = 0;
+        let source_challenge_key = self.source_challenge.key().clone();
+        self.source_challenge.transition_to(ChallengeStatus::Claimed, self.signer.key().clone(), source_challenge_key).unwrap();
+
+        self.target_challenge.prize_pool += amount;
+        self.target_challenge.rollover_pool += amount;
+
+        // emit events
+        challenge_emit!(
+            PrizePoolRolledOver {
+                actor: self.signer.key().clone(),
+                source_challenge_key: self.source_challenge.key().clone(),
+                target_challenge_key: self.target_challenge.key().clone(),
+                amount,
+            }
+        );
+
+        challenge_emit!(
+            NoWinnerPolicyApplied {
+                actor: self.signer.key().clone(),
+                challenge_key: self.source_challenge.key().clone(),
+                policy: NoWinnerPolicy::RollOver,
+                amount,
+            }
+        );
+
+        return Ok(());
+    }
+}
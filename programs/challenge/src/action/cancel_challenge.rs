@@ -28,7 +28,8 @@ impl<'info> CancelChallengeContext<'info> {
     pub fn execute(&mut self, params: CancelChallengeParams) -> Result<()> {
         // check if the challenge can be canceled
         if self.challenge.is_challenge_cancelable_for(&self.signer.key()) {
-            self.challenge.status = ChallengeStatus::Canceled;
+            let challenge_key = self.challenge.key().clone();
+            self.challenge.transition_to(ChallengeStatus::Canceled, self.signer.key().clone(), challenge_key).unwrap();
 
             // emit event
             challenge_emit!(
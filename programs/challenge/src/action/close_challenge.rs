@@ -0,0 +1,100 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct CloseChallengeParams {
+    pub challenge_id: String,
+}
+
+// Define the context, passed in parameters when trigger from challenge owner or administrators.
+#[derive(Accounts)]
+#[instruction(params: CloseChallengeParams)]
+pub struct CloseChallengeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    // the rent always goes back to the challenge owner
+    #[account(
+        mut,
+        address = challenge.owner,
+    )]
+    pub challenge_owner: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = challenge_owner,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        close = challenge_owner,
+        seeds = [METADATA_SEED, challenge.key().as_ref()],
+        bump = challenge_metadata.bump,
+    )]
+    pub challenge_metadata: Account<'info, ChallengeMetadata>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, challenge.reward_token_mint_account.as_ref()],
+        bump = challenge_treasury.bump,
+    )]
+    pub challenge_treasury: Account<'info, ChallengeTreasury>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseChallengeContext<'info> {
+    pub fn execute(&mut self, _params: CloseChallengeParams) -> Result<()> {
+        let challenge = &self.challenge;
+
+        // require administrator permission or challenge owner
+        if !(
+            self.challenge_registry.is_administrator(self.signer.key().clone())
+            || challenge.is_challenge_owner(self.signer.key().clone())
+        ) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // the challenge must be settled without outstanding liabilities
+        if !challenge.is_challenge_closable() {
+            return Err(ChallengeError::ChallengeCannotBeClosed.into());
+        }
+
+        // the early exit penalties and rolled over funds of a canceled challenge are owned by the platform
+        self.challenge_treasury.balance += challenge.get_unclaimable_pool().unwrap();
+
+        // the accounts are closed once the instruction succeeds, so we keep the history in the event
+        let reclaimed_lamports = challenge.to_account_info().lamports()
+            + self.challenge_metadata.to_account_info().lamports();
+
+        // emit event
+        challenge_emit!(
+            ChallengeClosed {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                id: challenge.id.clone(),
+                owner: challenge.owner.clone(),
+                status: challenge.status,
+                reward_token_mint_account: challenge.reward_token_mint_account.clone(),
+                total_players: challenge.players.len() as u64,
+                total_winners: challenge.get_total_winners().unwrap(),
+                total_deposit: challenge.get_total_deposit().unwrap(),
+                prize_pool: challenge.prize_pool,
+                collected_fee: challenge.collected_fee,
+                reclaimed_lamports,
+            }
+        );
+
+        return Ok(());
+    }
+}
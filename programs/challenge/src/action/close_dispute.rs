@@ -0,0 +1,63 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct CloseDisputeParams {
+    pub challenge_id: String,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: CloseDisputeParams)]
+pub struct CloseDisputeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    // the rent always goes back to the disputer
+    #[account(
+        mut,
+        address = dispute.disputer,
+    )]
+    pub disputer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = disputer,
+        seeds = [DISPUTE_SEED, challenge.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, ChallengeDispute>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseDisputeContext<'info> {
+    pub fn execute(&mut self, _params: CloseDisputeParams) -> Result<()> {
+        // the dispute is only closed once the challenge is settled
+        if !self.challenge.is_challenge_settled() {
+            return Err(ChallengeError::ChallengeCannotBeClosed.into());
+        }
+
+        self.challenge.is_dispute_opened = false;
+
+        // emit event
+        challenge_emit!(
+            DisputeClosed {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                dispute_key: self.dispute.key().clone(),
+                disputer: self.disputer.key().clone(),
+            }
+        );
+
+        return Ok(());
+    }
+}
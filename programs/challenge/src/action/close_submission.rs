@@ -0,0 +1,64 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct CloseSubmissionParams {
+    pub challenge_id: String,
+    pub player: Pubkey,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: CloseSubmissionParams)]
+pub struct CloseSubmissionContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    // the rent always goes back to the player who submitted the proof
+    #[account(
+        mut,
+        address = params.player,
+    )]
+    pub player: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = player,
+        seeds = [SUBMISSION_SEED, challenge.key().as_ref(), params.player.as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, ChallengeSubmission>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseSubmissionContext<'info> {
+    pub fn execute(&mut self, _params: CloseSubmissionParams) -> Result<()> {
+        // the submission is only closed once the challenge is settled
+        if !self.challenge.is_challenge_settled() {
+            return Err(ChallengeError::ChallengeCannotBeClosed.into());
+        }
+
+        self.challenge.total_submissions = self.challenge.total_submissions.saturating_sub(1);
+
+        // emit event
+        challenge_emit!(
+            SubmissionClosed {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                submission_key: self.submission.key().clone(),
+                player: self.player.key().clone(),
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -1,5 +1,15 @@
 use crate::*;
 
+// Define metadata params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ChallengeMetadataParams {
+    pub title: String,
+    pub description_uri: String,
+    pub category: String,
+    pub rules_hash: [u8; 32],
+    pub cover_image_uri: String,
+}
+
 // Define params
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
 pub struct CreateChallengeParams {
@@ -10,7 +20,55 @@ pub struct CreateChallengeParams {
     pub min_deposit: u64,
 
     // define the mint account for reward
-    pub reward_token_mint_account: Pubkey
+    pub reward_token_mint_account: Pubkey,
+
+    // define the timestamp after which players can no longer join
+    pub join_deadline: i64,
+
+    // define the timestamp at which the challenge ends
+    pub end_time: i64,
+
+    // define the optional max number of players
+    pub max_players: Option<u64>,
+
+    // define the optional max total deposit of a player
+    pub max_deposit_per_player: Option<u64>,
+
+    // define the optional max prize pool
+    pub max_prize_pool: Option<u64>,
+
+    // define the optional timestamp at which the challenge starts
+    pub start_time: Option<i64>,
+
+    // define the penalty in basis points deducted from the deposit of a leaving player
+    pub early_exit_penalty_bps: u16,
+
+    // define the ascending checkpoint timestamps players must check in before
+    pub milestones: Vec<i64>,
+
+    // define the optional judge recording check-ins on behalf of players
+    pub judge: Option<Pubkey>,
+
+    // define the outcome when the result is final without winners
+    pub no_winner_policy: NoWinnerPolicy,
+
+    // define the challenge receiving the prize pool when rolling over without winners
+    pub no_winner_rollover_target: Option<Pubkey>,
+
+    // define how the prize pool is split between winners
+    pub payout_model: PayoutModel,
+
+    // define the share in basis points of each rank when paying out by rank
+    pub payout_table: Vec<u16>,
+
+    // define who gets the amount left over by the payout shares
+    pub payout_remainder_policy: PayoutRemainderPolicy,
+
+    // define the fee in basis points taken from the prize pool for the challenge owner
+    pub creator_fee_bps: u16,
+
+    // define the descriptive metadata
+    pub metadata: ChallengeMetadataParams,
 }
 
 // Define the context, passed in parameters when trigger from deployer.
@@ -25,31 +83,47 @@ pub struct CreateChallengeContext<'info> {
         init,
         seeds = [CHALLENGE_SEED, params.id.as_bytes().as_ref()],
         payer = challenge_owner,
-        space = 10240,
+        space = CHALLENGE_ACCOUNT_SPACE,
         bump
     )]
     pub challenge: Account<'info, Challenge>,
 
+    #[account(
+        init,
+        seeds = [METADATA_SEED, challenge.key().as_ref()],
+        payer = challenge_owner,
+        space = ChallengeMetadata::SPACE,
+        bump
+    )]
+    pub challenge_metadata: Account<'info, ChallengeMetadata>,
+
     #[account(
         seeds = [PLATFORM_SEED],
         bump = challenge_registry.bump,
     )]
     pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
 
+    /// CHECK: the series the challenge id would be a round of, only read to know whether the id is reserved
+    pub challenge_series: UncheckedAccount<'info>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateChallengeContext<'info> {
-    pub fn execute(&mut self, params: CreateChallengeParams, bump: u8) -> Result<()> {
+    pub fn execute(&mut self, params: CreateChallengeParams, bump: u8, metadata_bump: u8) -> Result<()> {
         // set data
         let challenge = &mut self.challenge;
         challenge.owner = *self.challenge_owner.key;
-        challenge.id = params.id;
-        challenge.min_deposit = params.min_deposit;
-        challenge.reward_token_mint_account = params.reward_token_mint_account;
+        challenge.set_settings(&params).unwrap();
         challenge.bump = bump;
-        challenge.status = ChallengeStatus::Created;
+        challenge.status = ChallengeStatus::Open;
+
+        // set metadata
+        let challenge_metadata = &mut self.challenge_metadata;
+        challenge_metadata.bump = metadata_bump;
+        challenge_metadata.challenge = self.challenge.key().clone();
+        challenge_metadata.set_metadata(params.metadata).unwrap();
 
         // Now to validate data state
         self.handle_post_initialized().unwrap();
@@ -66,15 +140,6 @@ impl<'info> CreateChallengeContext<'info> {
         Ok(())
     }
 
-    // validate mint account
-    fn validate_mint_account(&self) -> Result<()> {
-        if !self.challenge_registry.is_mint_account_enabled(self.challenge.reward_token_mint_account) {
-            return Err(ChallengeError::UnAllowedMintToken.into());
-        }
-
-        return Ok(());
-    }
-
     fn handle_post_initialized(&mut self) -> Result<()> {
         if self.challenge.id == "".to_string() {
             return Err(ChallengeError::InvalidValue.into());
@@ -88,8 +153,11 @@ impl<'info> CreateChallengeContext<'info> {
             return Err(ChallengeError::InvalidValue.into());
         }
 
-        // Check if user want to offer un-allowed mint tokens
-        self.validate_mint_account().unwrap();
+        // The settings must be valid against the registry
+        self.challenge.validate_with_registry(
+            &self.challenge_registry,
+            Some(&self.challenge_series.to_account_info())
+        ).unwrap();
 
         // ok
         return Ok(());
@@ -0,0 +1,104 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct CreateChallengeFromTemplateParams {
+    // id of the template
+    pub template_id: String,
+
+    // define the creator settings, validated against the template
+    pub challenge: CreateChallengeParams,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: CreateChallengeFromTemplateParams)]
+pub struct CreateChallengeFromTemplateContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub challenge_owner: Signer<'info>,
+
+    #[account(
+        seeds = [TEMPLATE_SEED, params.template_id.as_bytes().as_ref()],
+        bump = challenge_template.bump,
+    )]
+    pub challenge_template: Account<'info, ChallengeTemplate>,
+
+    #[account(
+        init,
+        seeds = [CHALLENGE_SEED, params.challenge.id.as_bytes().as_ref()],
+        payer = challenge_owner,
+        space = CHALLENGE_ACCOUNT_SPACE,
+        bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init,
+        seeds = [METADATA_SEED, challenge.key().as_ref()],
+        payer = challenge_owner,
+        space = ChallengeMetadata::SPACE,
+        bump
+    )]
+    pub challenge_metadata: Account<'info, ChallengeMetadata>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    /// CHECK: the series the challenge id would be a round of, only read to know whether the id is reserved
+    pub challenge_series: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateChallengeFromTemplateContext<'info> {
+    pub fn execute(&mut self, params: CreateChallengeFromTemplateParams, bump: u8, metadata_bump: u8) -> Result<()> {
+        // the template must be enabled
+        if !self.challenge_template.is_enabled {
+            return Err(ChallengeError::TemplateIsNotAvailable.into());
+        }
+
+        // set data, the payout model and fee are defined by the template
+        let challenge = &mut self.challenge;
+        challenge.owner = *self.challenge_owner.key;
+        challenge.set_settings(&params.challenge).unwrap();
+        challenge.payout_model = self.challenge_template.payout_model;
+        challenge.fee_bps = self.challenge_template.fee_bps;
+        challenge.template = Some(self.challenge_template.key().clone());
+
+        // players cannot deposit more than the template allows
+        if challenge.max_deposit_per_player.is_none() {
+            challenge.max_deposit_per_player = Some(self.challenge_template.max_deposit);
+        }
+        challenge.bump = bump;
+        challenge.status = ChallengeStatus::Open;
+
+        // set metadata
+        let challenge_metadata = &mut self.challenge_metadata;
+        challenge_metadata.bump = metadata_bump;
+        challenge_metadata.challenge = self.challenge.key().clone();
+        challenge_metadata.set_metadata(params.challenge.metadata).unwrap();
+
+        // The creator settings must be valid against the registry and within the template
+        self.challenge.validate_with_registry(
+            &self.challenge_registry,
+            Some(&self.challenge_series.to_account_info())
+        ).unwrap();
+        self.challenge_template.validate_challenge(&self.challenge).unwrap();
+
+        challenge_emit!(
+            ChallengeCreated {
+                id: self.challenge.id.to_string(),
+                challenge_key: self.challenge.key().clone(),
+                actor: self.challenge.owner.clone()
+            }
+        );
+
+        // ok
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct CreateChallengeSeriesParams {
+    // offchain id used as a ref
+    pub id: String,
+
+    // define minimum deposit of every round
+    pub min_deposit: u64,
+
+    // define the mint account for reward of every round
+    pub reward_token_mint_account: Pubkey,
+
+    // define how long players can join after a round is spawned
+    pub join_duration: i64,
+
+    // define how long a round lasts after it is spawned
+    pub duration: i64,
+
+    // define whether zero-winner prize pools roll over into the next round
+    pub rollover_enabled: bool,
+}
+
+// Define the context, passed in parameters when trigger from series owner.
+#[derive(Accounts)]
+#[instruction(params: CreateChallengeSeriesParams)]
+pub struct CreateChallengeSeriesContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub series_owner: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [SERIES_SEED, params.id.as_bytes().as_ref()],
+        payer = series_owner,
+        space = ChallengeSeries::SPACE,
+        bump
+    )]
+    pub challenge_series: Account<'info, ChallengeSeries>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateChallengeSeriesContext<'info> {
+    pub fn execute(&mut self, params: CreateChallengeSeriesParams, bump: u8) -> Result<()> {
+        if params.id == "".to_string() || params.id.len() > MAX_SERIES_ID_LENGTH {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        if params.join_duration <= 0 || params.duration < params.join_duration {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // Check if user want to offer un-allowed mint tokens
+        if !self.challenge_registry.is_mint_account_enabled(params.reward_token_mint_account) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        // set data
+        let challenge_series = &mut self.challenge_series;
+        challenge_series.id = params.id;
+        challenge_series.bump = bump;
+        challenge_series.owner = *self.series_owner.key;
+        challenge_series.reward_token_mint_account = params.reward_token_mint_account;
+        challenge_series.min_deposit = params.min_deposit;
+        challenge_series.join_duration = params.join_duration;
+        challenge_series.duration = params.duration;
+        challenge_series.round = 0;
+        challenge_series.rollover_enabled = params.rollover_enabled;
+
+        challenge_emit!(
+            ChallengeSeriesCreated {
+                id: self.challenge_series.id.to_string(),
+                series_key: self.challenge_series.key().clone(),
+                actor: self.series_owner.key().clone()
+            }
+        );
+
+        // ok
+        Ok(())
+    }
+}
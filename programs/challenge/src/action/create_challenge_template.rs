@@ -0,0 +1,86 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ChallengeTemplateParams {
+    // id of the template
+    pub id: String,
+
+    // define whether challenges can be created from the template
+    pub is_enabled: bool,
+
+    // define the mint account for reward
+    pub reward_token_mint_account: Pubkey,
+
+    // define the range a player can deposit
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+
+    // define the range of the challenge duration
+    pub min_duration: i64,
+    pub max_duration: i64,
+
+    // define how the prize pool is split between winners
+    pub payout_model: PayoutModel,
+
+    // define the fee in basis points taken from the prize pool
+    pub fee_bps: u16,
+}
+
+// Define the context, passed in parameters when trigger from administrators.
+#[derive(Accounts)]
+#[instruction(params: ChallengeTemplateParams)]
+pub struct CreateChallengeTemplateContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [TEMPLATE_SEED, params.id.as_bytes().as_ref()],
+        payer = signer,
+        space = ChallengeTemplate::SPACE,
+        bump
+    )]
+    pub challenge_template: Account<'info, ChallengeTemplate>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateChallengeTemplateContext<'info> {
+    pub fn execute(&mut self, params: ChallengeTemplateParams, bump: u8) -> Result<()> {
+        // require administrator only
+        if !self.challenge_registry.is_administrator(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // Check if the template offers un-allowed mint tokens
+        if !self.challenge_registry.is_mint_account_enabled(params.reward_token_mint_account) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        // set data
+        let challenge_template = &mut self.challenge_template;
+        challenge_template.bump = bump;
+        challenge_template.set_template(params).unwrap();
+
+        // emit event
+        challenge_emit!(
+            ChallengeTemplateUpdated {
+                actor: self.signer.key().clone(),
+                template_key: self.challenge_template.key().clone(),
+                id: self.challenge_template.id.clone(),
+                is_enabled: self.challenge_template.is_enabled,
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -23,6 +23,15 @@ pub struct CreateTokenVaultContext<'info> {
     )]
     pub challenge_token_vault: Account<'info, TokenAccount>,
 
+    #[account(
+        init,
+        seeds = [TREASURY_SEED, mint_account.key().as_ref()],
+        payer = signer,
+        space = ChallengeTreasury::SPACE,
+        bump
+    )]
+    pub challenge_treasury: Account<'info, ChallengeTreasury>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -34,7 +43,7 @@ pub struct CreateTokenVaultContext<'info> {
 }
 
 impl<'info> CreateTokenVaultContext<'info> {
-    pub fn execute(&mut self, bump: u8) -> Result<()> {
+    pub fn execute(&mut self, bump: u8, treasury_bump: u8) -> Result<()> {
         // must be one of the administrators
         if !self.challenge_registry.is_administrator(self.signer.key()) {
             return Err(ChallengeError::OnlyAdministrator.into());
@@ -51,10 +60,14 @@ impl<'info> CreateTokenVaultContext<'info> {
                 bump,
                 mint_account: self.mint_account.key().clone(),
                 token_account: self.challenge_token_vault.key(),
-                is_enabled: true
+                is_enabled: true,
             }
         );
 
+        // the treasury accounts the platform funds held in the vault
+        self.challenge_treasury.bump = treasury_bump;
+        self.challenge_treasury.mint_account = self.mint_account.key().clone();
+
         // emit event
         challenge_emit!(
             VaultCreated {
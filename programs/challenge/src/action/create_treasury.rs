@@ -0,0 +1,56 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateTreasuryContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        init,
+        seeds = [TREASURY_SEED, mint_account.key().as_ref()],
+        payer = signer,
+        space = ChallengeTreasury::SPACE,
+        bump
+    )]
+    pub challenge_treasury: Account<'info, ChallengeTreasury>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateTreasuryContext<'info> {
+    pub fn execute(&mut self, treasury_bump: u8) -> Result<()> {
+        // must be one of the administrators
+        if !self.challenge_registry.is_administrator(self.signer.key()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // the mint must already have its token vault
+        if !self.challenge_registry.is_mint_account_existed(self.mint_account.key().clone()) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        // the treasury accounts the platform funds held in the vault
+        self.challenge_treasury.bump = treasury_bump;
+        self.challenge_treasury.mint_account = self.mint_account.key().clone();
+
+        // emit event
+        challenge_emit!(
+            TreasuryCreated {
+                actor: self.signer.key().clone(),
+                treasury_key: self.challenge_treasury.key().clone(),
+                mint_account: self.mint_account.key().clone()
+            }
+        );
+
+        Ok(())
+    }
+}
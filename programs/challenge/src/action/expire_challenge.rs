@@ -0,0 +1,56 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ExpireChallengeParams {
+    id: String,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: ExpireChallengeParams)]
+pub struct ExpireChallengeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExpireChallengeContext<'info> {
+    pub fn execute(&mut self, _params: ExpireChallengeParams) -> Result<()> {
+        // the challenge must be abandoned past the grace period
+        if !self.challenge.is_challenge_expirable(self.challenge_registry.expiry_grace_period) {
+            return Err(ChallengeError::ChallengeCannotBeExpired.into());
+        }
+
+        // players can now withdraw their deposits
+        let challenge_key = self.challenge.key().clone();
+        self.challenge.transition_to(ChallengeStatus::Canceled, self.signer.key().clone(), challenge_key).unwrap();
+
+        // emit event
+        challenge_emit!(
+            ChallengeExpired {
+                actor: self.signer.key().clone(),
+                status: ChallengeStatus::Canceled,
+                id: self.challenge.id.clone(),
+                challenge_key: self.challenge.key().clone(),
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ForceCancelChallengeParams {
+    pub challenge_id: String,
+    pub reason_code: u16,
+    pub reason_uri: String,
+}
+
+// Define the context, passed in parameters when trigger from administrators.
+#[derive(Accounts)]
+#[instruction(params: ForceCancelChallengeParams)]
+pub struct ForceCancelChallengeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForceCancelChallengeContext<'info> {
+    pub fn execute(&mut self, params: ForceCancelChallengeParams) -> Result<()> {
+        // require administrator only
+        if !self.challenge_registry.is_administrator(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // the challenge can only be canceled before any claim
+        if !self.challenge.is_challenge_force_cancelable() {
+            return Err(ChallengeError::ChallengeCannotBeCanceled.into());
+        }
+
+        if params.reason_uri.len() > MAX_URI_LENGTH {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // record the reason, players can now withdraw their deposits
+        self.challenge.cancel_reason_code = params.reason_code;
+        self.challenge.cancel_reason_uri = params.reason_uri;
+        let challenge_key = self.challenge.key().clone();
+        self.challenge.transition_to(ChallengeStatus::Canceled, self.signer.key().clone(), challenge_key).unwrap();
+
+        // emit event
+        challenge_emit!(
+            ChallengeCanceled {
+                actor: self.signer.key().clone(),
+                status: ChallengeStatus::Canceled,
+                id: self.challenge.id.clone(),
+                challenge_key: self.challenge.key().clone(),
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -8,6 +8,27 @@ pub struct InitializeChallengePlatformParams {
 
     // define max allowed options can be asked.
     pub allowed_administrators: Vec<Pubkey>,
+
+    // define the period after the challenge end before anyone can expire it
+    pub expiry_grace_period: i64,
+
+    // define the period after winner submission during which participants can dispute
+    pub dispute_window: i64,
+
+    // define the period after winner submission during which the winner list can be amended
+    pub correction_window: i64,
+
+    // define the protocol fee in basis points taken from the prize pool once the result is final
+    pub protocol_fee_bps: u16,
+
+    // define the max creator fee in basis points a challenge owner can set
+    pub max_creator_fee_bps: u16,
+
+    // define the share in basis points of the referral fee source paid out to referrers
+    pub referral_bps: u16,
+
+    // define which fee pays the referral rewards
+    pub referral_fee_source: ReferralFeeSource,
 }
 
 // Define the context, passed in parameters when trigger from deployer.
@@ -42,6 +63,23 @@ impl<'info> InitializeChallengePlatformContext<'info> {
         challenge_registry.owner = *self.owner.key;
         challenge_registry.allowed_mint_accounts = params.allowed_mint_accounts;
         challenge_registry.allowed_administrators = params.allowed_administrators;
+        challenge_registry.expiry_grace_period = params.expiry_grace_period;
+        challenge_registry.dispute_window = params.dispute_window;
+        challenge_registry.correction_window = params.correction_window;
+        challenge_registry.protocol_fee_bps = params.protocol_fee_bps;
+        challenge_registry.max_creator_fee_bps = params.max_creator_fee_bps;
+        challenge_registry.referral_bps = params.referral_bps;
+        challenge_registry.referral_fee_source = params.referral_fee_source;
+
+        // the fees cannot exceed the prize pool
+        if !challenge_registry.is_fee_settings_valid() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // the windows cannot be negative
+        if !challenge_registry.is_window_settings_valid() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
 
         Ok(())
     }
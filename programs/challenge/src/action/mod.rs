@@ -4,18 +4,70 @@ pub mod utils;
 pub mod initialize_challenge_program;
 pub mod update_challenge_registry;
 pub mod create_challenge;
+pub mod update_challenge;
+pub mod update_challenge_metadata;
 pub mod cancel_challenge;
 pub mod create_token_vault;
 pub mod transfer_assets_to_vault;
 pub mod transfer_assets_from_vault;
 pub mod submit_winner_list;
+pub mod expire_challenge;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod create_challenge_series;
+pub mod spawn_series_round;
+pub mod record_check_in;
+pub mod settle_milestone_challenge;
+pub mod submit_proof;
+pub mod review_proof;
+pub mod apply_no_winner_policy;
+pub mod apply_no_winner_rollover;
+pub mod amend_winner_list;
+pub mod create_challenge_template;
+pub mod update_challenge_template;
+pub mod create_challenge_from_template;
+pub mod update_challenge_from_template;
+pub mod transition_challenge_status;
+pub mod force_cancel_challenge;
+pub mod close_challenge;
+pub mod close_dispute;
+pub mod close_submission;
+pub mod withdraw_fees;
+pub mod set_mint_protocol_fee;
+pub mod create_treasury;
 
 pub use utils::*;
 pub use initialize_challenge_program::*;
 pub use update_challenge_registry::*;
 pub use create_challenge::*;
+pub use update_challenge::*;
+pub use update_challenge_metadata::*;
 pub use cancel_challenge::*;
 pub use create_token_vault::*;
 pub use transfer_assets_to_vault::*;
 pub use transfer_assets_from_vault::*;
-pub use submit_winner_list::*;
\ No newline at end of file
+pub use submit_winner_list::*;
+pub use expire_challenge::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use create_challenge_series::*;
+pub use spawn_series_round::*;
+pub use record_check_in::*;
+pub use settle_milestone_challenge::*;
+pub use submit_proof::*;
+pub use review_proof::*;
+pub use apply_no_winner_policy::*;
+pub use apply_no_winner_rollover::*;
+pub use amend_winner_list::*;
+pub use create_challenge_template::*;
+pub use update_challenge_template::*;
+pub use create_challenge_from_template::*;
+pub use update_challenge_from_template::*;
+pub use transition_challenge_status::*;
+pub use force_cancel_challenge::*;
+pub use close_challenge::*;
+pub use close_dispute::*;
+pub use close_submission::*;
+pub use withdraw_fees::*;
+pub use set_mint_protocol_fee::*;
+pub use create_treasury::*;
\ No newline at end of file
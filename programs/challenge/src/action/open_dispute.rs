@@ -0,0 +1,82 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct OpenDisputeParams {
+    pub challenge_id: String,
+    pub reason_uri: String,
+}
+
+// Define the context, passed in parameters when trigger from participants.
+#[derive(Accounts)]
+#[instruction(params: OpenDisputeParams)]
+pub struct OpenDisputeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init,
+        seeds = [DISPUTE_SEED, challenge.key().as_ref()],
+        payer = signer,
+        space = ChallengeDispute::SPACE,
+        bump
+    )]
+    pub dispute: Account<'info, ChallengeDispute>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenDisputeContext<'info> {
+    pub fn execute(&mut self, params: OpenDisputeParams, bump: u8) -> Result<()> {
+        // only participants can dispute the winner list
+        if !self.challenge.is_player(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyParticipant.into());
+        }
+
+        // the dispute window must be still open
+        if !self.challenge.is_challenge_open_for_dispute() {
+            return Err(ChallengeError::DisputeIsNotAvailable.into());
+        }
+
+        if params.reason_uri.len() > MAX_URI_LENGTH {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // record the dispute
+        let clock = Clock::get().unwrap();
+        let dispute = &mut self.dispute;
+        dispute.bump = bump;
+        dispute.challenge = self.challenge.key().clone();
+        dispute.disputer = self.signer.key().clone();
+        dispute.reason_uri = params.reason_uri.clone();
+        dispute.opened_at = clock.unix_timestamp;
+        dispute.status = DisputeStatus::Opened;
+
+        // the dispute must be closed before the challenge
+        self.challenge.is_dispute_opened = true;
+
+        // claiming is blocked until administrators resolve the dispute
+        let challenge_key = self.challenge.key().clone();
+        self.challenge.transition_to(ChallengeStatus::Disputed, self.signer.key().clone(), challenge_key).unwrap();
+
+        // emit event
+        challenge_emit!(
+            DisputeOpened {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                dispute_key: self.dispute.key().clone(),
+                reason_uri: params.reason_uri,
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct RecordCheckInParams {
+    // offchain id used as a ref
+    pub challenge_id: String,
+
+    // define the player checking in
+    pub player: Pubkey,
+
+    // define the index of the checked in milestone
+    pub milestone_index: u8,
+}
+
+// Define the context, passed in parameters when trigger from players or the judge.
+#[derive(Accounts)]
+#[instruction(params: RecordCheckInParams)]
+pub struct RecordCheckInContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RecordCheckInContext<'info> {
+    pub fn execute(&mut self, params: RecordCheckInParams) -> Result<()> {
+        let challenge = &mut self.challenge;
+
+        // players check in themselves unless the challenge has a judge
+        if !challenge.is_check_in_recorder_for(self.signer.key().clone(), params.player.clone()) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // the milestone must be open for check-ins
+        if !challenge.is_check_in_open_for(params.milestone_index as usize) {
+            return Err(ChallengeError::CheckInIsNotAvailable.into());
+        }
+
+        // players who missed a passed milestone are eliminated first
+        let eliminated_players = challenge.eliminate_missed_players().unwrap();
+
+        // now we record the check-in
+        challenge.record_check_in(params.player.clone(), params.milestone_index as usize).unwrap();
+
+        // emit events
+        for player in eliminated_players {
+            challenge_emit!(
+                PlayerEliminated {
+                    actor: self.signer.key().clone(),
+                    challenge_key: challenge.key().clone(),
+                    player,
+                }
+            );
+        }
+
+        challenge_emit!(
+            CheckInRecorded {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                player: params.player,
+                milestone_index: params.milestone_index,
+            }
+        );
+
+        return Ok(());
+    }
+}
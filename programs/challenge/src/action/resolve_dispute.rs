@@ -0,0 +1,105 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub enum ResolveDisputeActionType {
+    #[default]
+    UpholdWinnerList,
+    ReplaceWinnerList,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ResolveDisputeParams {
+    pub challenge_id: String,
+    pub action_type: ResolveDisputeActionType,
+    pub winner_list: Vec<Pubkey>,
+}
+
+// Define the context, passed in parameters when trigger from administrators.
+#[derive(Accounts)]
+#[instruction(params: ResolveDisputeParams)]
+pub struct ResolveDisputeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, challenge.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, ChallengeDispute>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveDisputeContext<'info> {
+    pub fn execute(&mut self, params: ResolveDisputeParams) -> Result<()> {
+        // require administrator only
+        if !self.challenge_registry.is_administrator(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // the dispute can only be resolved once
+        if self.dispute.status != DisputeStatus::Opened
+            || self.challenge.status != ChallengeStatus::Disputed {
+            return Err(ChallengeError::DisputeAlreadyResolved.into());
+        }
+
+        // replace the winner list if requested
+        if params.action_type == ResolveDisputeActionType::ReplaceWinnerList {
+            self.challenge.set_winner_list(params.winner_list.clone()).unwrap();
+            self.dispute.status = DisputeStatus::Replaced;
+        } else {
+            self.dispute.status = DisputeStatus::Upheld;
+        }
+
+        let clock = Clock::get().unwrap();
+        self.dispute.resolver = self.signer.key().clone();
+        self.dispute.resolved_at = clock.unix_timestamp;
+        self.challenge.is_dispute_resolved = true;
+
+        // winners can now claim
+        let challenge_key = self.challenge.key().clone();
+        self.challenge.transition_to(ChallengeStatus::Finalized, self.signer.key().clone(), challenge_key).unwrap();
+
+        challenge_emit!(
+            ChallengeFinalized {
+                actor: self.signer.key().clone(),
+                challenge_key,
+                id: self.challenge.id.to_string(),
+                status: self.challenge.status,
+            }
+        );
+
+        // emit event
+        challenge_emit!(
+            DisputeResolved {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                dispute_key: self.dispute.key().clone(),
+                status: self.dispute.status,
+                winner_list: self.challenge.players.iter()
+                    .filter(|player| player.is_winner == true)
+                    .map(|player| player.public_key)
+                    .collect(),
+            }
+        );
+
+        // ok
+        return Ok(());
+    }
+}
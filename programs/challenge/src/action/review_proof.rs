@@ -0,0 +1,92 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub enum ReviewProofActionType {
+    #[default]
+    AcceptProof,
+    RejectProof,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct ReviewProofParams {
+    pub challenge_id: String,
+    pub player: Pubkey,
+    pub action_type: ReviewProofActionType,
+}
+
+// Define the context, passed in parameters when trigger from administrators or challenge owner.
+#[derive(Accounts)]
+#[instruction(params: ReviewProofParams)]
+pub struct ReviewProofContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        seeds = [SUBMISSION_SEED, challenge.key().as_ref(), params.player.as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, ChallengeSubmission>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReviewProofContext<'info> {
+    pub fn execute(&mut self, params: ReviewProofParams) -> Result<()> {
+        // require administrator permission or challenge owner
+        if !(
+            self.challenge_registry.is_administrator(self.signer.key().clone())
+            || self.challenge.is_challenge_owner(self.signer.key().clone())
+        ) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // proofs can only be reviewed before the result is final
+        if !self.challenge.is_challenge_open_for_proof_review() {
+            return Err(ChallengeError::SubmissionIsNotAvailable.into());
+        }
+
+        // the submission can only be reviewed once
+        if self.submission.status != SubmissionStatus::Pending {
+            return Err(ChallengeError::SubmissionAlreadyReviewed.into());
+        }
+
+        if params.action_type == ReviewProofActionType::AcceptProof {
+            self.submission.status = SubmissionStatus::Accepted;
+        } else {
+            self.submission.status = SubmissionStatus::Rejected;
+        }
+
+        let clock = Clock::get().unwrap();
+        self.submission.reviewer = self.signer.key().clone();
+        self.submission.reviewed_at = clock.unix_timestamp;
+
+        // emit event
+        challenge_emit!(
+            ProofReviewed {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                submission_key: self.submission.key().clone(),
+                player: self.submission.player.clone(),
+                status: self.submission.status,
+            }
+        );
+
+        // ok
+        return Ok(());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct SetMintProtocolFeeParams {
+    pub mint_account: Pubkey,
+
+    // none falls back to the registry protocol fee
+    pub protocol_fee_bps: Option<u16>,
+}
+
+// Define the context, passed in parameters when trigger from administrators.
+#[derive(Accounts)]
+#[instruction(params: SetMintProtocolFeeParams)]
+pub struct SetMintProtocolFeeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMintProtocolFeeContext<'info> {
+    pub fn execute(&mut self, params: SetMintProtocolFeeParams) -> Result<()> {
+        // require administrator only
+        if !self.challenge_registry.is_administrator(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // only whitelisted mints can be charged
+        if !self.challenge_registry.is_mint_account_existed(params.mint_account) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        self.challenge_registry.set_mint_protocol_fee(params.mint_account, params.protocol_fee_bps).unwrap();
+
+        // the fees cannot exceed the prize pool
+        if !self.challenge_registry.is_fee_settings_valid() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // emit event
+        challenge_emit!(
+            MintProtocolFeeUpdated {
+                actor: self.signer.key().clone(),
+                mint_account: params.mint_account,
+                protocol_fee_bps: params.protocol_fee_bps,
+            }
+        );
+
+        return Ok(());
+    }
+}
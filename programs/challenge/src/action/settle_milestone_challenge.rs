@@ -0,0 +1,84 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct SettleMilestoneChallengeParams {
+    // offchain id used as a ref
+    pub challenge_id: String,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: SettleMilestoneChallengeParams)]
+pub struct SettleMilestoneChallengeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleMilestoneChallengeContext<'info> {
+    pub fn execute(&mut self, _params: SettleMilestoneChallengeParams) -> Result<()> {
+        let challenge = &mut self.challenge;
+
+        // only milestone challenges are settled automatically
+        if !challenge.is_milestone_challenge() || !challenge.is_challenge_in_progress() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // results can only be settled once the challenge has ended
+        if !challenge.is_challenge_ended() {
+            return Err(ChallengeError::ChallengeNotEndedYet.into());
+        }
+
+        // every milestone has passed, the survivors are the winners
+        let eliminated_players = challenge.eliminate_missed_players().unwrap();
+        let winner_list = challenge.get_survivors().unwrap();
+
+        let challenge_key = challenge.key().clone();
+        challenge.submit_result(
+            winner_list.clone(),
+            self.challenge_registry.dispute_window,
+            self.signer.key().clone(),
+            challenge_key,
+        ).unwrap();
+
+        // emit events
+        for player in eliminated_players {
+            challenge_emit!(
+                PlayerEliminated {
+                    actor: self.signer.key().clone(),
+                    challenge_key: challenge.key().clone(),
+                    player,
+                }
+            );
+        }
+
+        challenge_emit!(
+            WinnerListSubmitted {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                id: challenge.id.clone(),
+                winner_list,
+                dispute_deadline: challenge.dispute_deadline,
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct SpawnSeriesRoundParams {
+    // id of the series
+    pub series_id: String,
+
+    // derived id of the next round, must match the series round id
+    pub challenge_id: String,
+
+    // define the descriptive metadata of the round
+    pub metadata: ChallengeMetadataParams,
+}
+
+// Define the context, passed in parameters when trigger from series owner.
+#[derive(Accounts)]
+#[instruction(params: SpawnSeriesRoundParams)]
+pub struct SpawnSeriesRoundContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub series_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SERIES_SEED, params.series_id.as_bytes().as_ref()],
+        bump = challenge_series.bump,
+    )]
+    pub challenge_series: Account<'info, ChallengeSeries>,
+
+    #[account(
+        init,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        payer = series_owner,
+        space = CHALLENGE_ACCOUNT_SPACE,
+        bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init,
+        seeds = [METADATA_SEED, challenge.key().as_ref()],
+        payer = series_owner,
+        space = ChallengeMetadata::SPACE,
+        bump
+    )]
+    pub challenge_metadata: Account<'info, ChallengeMetadata>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SpawnSeriesRoundContext<'info> {
+    pub fn execute(&mut self, params: SpawnSeriesRoundParams, bump: u8, metadata_bump: u8) -> Result<()> {
+        // only the series owner can spawn rounds
+        if !self.challenge_series.is_series_owner(self.series_owner.key().clone()) {
+            return Err(ChallengeError::OnlyOwner.into());
+        }
+
+        // the round id must be derived from the series
+        let round = self.challenge_series.round + 1;
+        if params.challenge_id != self.challenge_series.get_round_id(round) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // Check if the template mint is still allowed
+        if !self.challenge_registry.is_mint_account_enabled(self.challenge_series.reward_token_mint_account) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        // set data from the series template
+        let clock = Clock::get().unwrap();
+        let challenge = &mut self.challenge;
+        challenge.owner = *self.series_owner.key;
+        challenge.id = params.challenge_id;
+        challenge.min_deposit = self.challenge_series.min_deposit;
+        challenge.reward_token_mint_account = self.challenge_series.reward_token_mint_account;
+        challenge.join_deadline = clock.unix_timestamp + self.challenge_series.join_duration;
+        challenge.end_time = clock.unix_timestamp + self.challenge_series.duration;
+        challenge.series = Some(self.challenge_series.key().clone());
+        challenge.round = round;
+        challenge.bump = bump;
+        challenge.status = ChallengeStatus::Open;
+
+        // zero-winner rounds roll over into the next round
+        if self.challenge_series.rollover_enabled {
+            challenge.no_winner_policy = NoWinnerPolicy::RollOver;
+            challenge.no_winner_rollover_target = Some(self.challenge_series.get_round_key(round + 1));
+        }
+        challenge.validate_settings().unwrap();
+
+        // set metadata
+        let challenge_metadata = &mut self.challenge_metadata;
+        challenge_metadata.bump = metadata_bump;
+        challenge_metadata.challenge = self.challenge.key().clone();
+        challenge_metadata.set_metadata(params.metadata).unwrap();
+
+        // move the round counter
+        self.challenge_series.round = round;
+
+        challenge_emit!(
+            ChallengeCreated {
+                id: self.challenge.id.to_string(),
+                challenge_key: self.challenge.key().clone(),
+                actor: self.challenge.owner.clone()
+            }
+        );
+
+        challenge_emit!(
+            SeriesRoundSpawned {
+                actor: self.series_owner.key().clone(),
+                series_key: self.challenge_series.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                round,
+            }
+        );
+
+        // ok
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct SubmitProofParams {
+    pub challenge_id: String,
+    pub evidence_uri: String,
+    pub content_hash: [u8; 32],
+}
+
+// Define the context, passed in parameters when trigger from participants.
+#[derive(Accounts)]
+#[instruction(params: SubmitProofParams)]
+pub struct SubmitProofContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init,
+        seeds = [SUBMISSION_SEED, challenge.key().as_ref(), signer.key().as_ref()],
+        payer = signer,
+        space = ChallengeSubmission::SPACE,
+        bump
+    )]
+    pub submission: Account<'info, ChallengeSubmission>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitProofContext<'info> {
+    pub fn execute(&mut self, params: SubmitProofParams, bump: u8) -> Result<()> {
+        // only participants can submit proofs
+        if !self.challenge.is_player(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyParticipant.into());
+        }
+
+        // proofs can only be submitted before the result
+        if !self.challenge.is_challenge_open_for_submission() {
+            return Err(ChallengeError::SubmissionIsNotAvailable.into());
+        }
+
+        if params.evidence_uri.len() > MAX_URI_LENGTH {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // record the submission
+        let clock = Clock::get().unwrap();
+        let submission = &mut self.submission;
+        submission.bump = bump;
+        submission.challenge = self.challenge.key().clone();
+        submission.player = self.signer.key().clone();
+        submission.evidence_uri = params.evidence_uri.clone();
+        submission.content_hash = params.content_hash;
+        submission.submitted_at = clock.unix_timestamp;
+        submission.status = SubmissionStatus::Pending;
+
+        // the submission must be closed before the challenge
+        self.challenge.total_submissions += 1;
+
+        // emit event
+        challenge_emit!(
+            ProofSubmitted {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                submission_key: self.submission.key().clone(),
+                evidence_uri: params.evidence_uri,
+                content_hash: params.content_hash,
+            }
+        );
+
+        return Ok(());
+    }
+}
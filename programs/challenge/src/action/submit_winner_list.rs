@@ -43,22 +43,40 @@ impl<'info> SubmitWinnersContext<'info> {
             return Err(ChallengeError::InvalidValue.into());
         }
 
-        // must be open for participants first
-        if !challenge.is_challenge_open_for_participants() {
+        // must be in progress first
+        if !challenge.is_challenge_in_progress() {
             return Err(ChallengeError::InvalidValue.into());
         }
 
-        // now we mutate the winner list
-        params.winner_list.into_iter().for_each(|winner| {
-            let player = challenge.find_player_for_mutation(
-                winner
-            ).unwrap();
+        // results can only be submitted once the challenge has ended
+        if !challenge.is_challenge_ended() {
+            return Err(ChallengeError::ChallengeNotEndedYet.into());
+        }
+
+        // milestone challenges are settled by check-ins
+        if challenge.is_milestone_challenge() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
 
-            player.is_winner = true;
-        });
+        // now we mutate the winner list, it can be disputed until the window lapses
+        let challenge_key = challenge.key().clone();
+        challenge.submit_result(
+            params.winner_list.clone(),
+            self.challenge_registry.dispute_window,
+            self.signer.key().clone(),
+            challenge_key,
+        ).unwrap();
 
-        // the challenge status now be updated into finalized
-        challenge.status = ChallengeStatus::Finalized;
+        // emit event
+        challenge_emit!(
+            WinnerListSubmitted {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                id: challenge.id.clone(),
+                winner_list: params.winner_list,
+                dispute_deadline: challenge.dispute_deadline,
+            }
+        );
 
         return Ok(());
     }
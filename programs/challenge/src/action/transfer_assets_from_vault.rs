@@ -6,7 +6,11 @@ pub enum TransferAssetsFromVaultActionType {
     #[default]
     Claiming,
     Withdrawing,
-    AdminWithdrawingDonatePool
+    AdminWithdrawingDonatePool,
+    LeavingChallenge,
+    RefundingDonation,
+    ClaimingCreatorFee,
+    ClaimingReferralReward
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
@@ -22,6 +26,9 @@ pub struct TransferAssetsFromVaultContext<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+    constraint = mint_account.key() == challenge.reward_token_mint_account @ ChallengeError::UnAllowedMintToken,
+    )]
     pub mint_account: Account<'info, Mint>,
 
     #[account(
@@ -48,6 +55,13 @@ pub struct TransferAssetsFromVaultContext<'info> {
     )]
     pub challenge_token_vault: Account<'info, TokenAccount>,
 
+    #[account(
+    mut,
+    seeds = [TREASURY_SEED, mint_account.key().as_ref()],
+    bump = challenge_treasury.bump
+    )]
+    pub challenge_treasury: Account<'info, ChallengeTreasury>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -72,56 +86,118 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
             return self.admin_withdraw_donate_pool(params);
         }
 
+        // Check and route for leaving
+        if params.action_type == TransferAssetsFromVaultActionType::LeavingChallenge {
+            return self.leave(params);
+        }
+
+        // Check and route for donation refund
+        if params.action_type == TransferAssetsFromVaultActionType::RefundingDonation {
+            return self.refund_donation(params);
+        }
+
+        // Check and route for creator fee
+        if params.action_type == TransferAssetsFromVaultActionType::ClaimingCreatorFee {
+            return self.claim_creator_fee(params);
+        }
+
+        // Check and route for referral reward
+        if params.action_type == TransferAssetsFromVaultActionType::ClaimingReferralReward {
+            return self.claim_referral_reward(params);
+        }
+
         return Err(ChallengeError::InvalidValue.into());
     }
 
+    // make the result final, the fees and the payout remainder are settled once
+    fn finalize_result(&mut self) -> Result<()> {
+        let challenge = self.challenge.borrow_mut();
+        let challenge_key = challenge.key().clone();
+
+        // the undisputed winner list becomes final once the dispute window lapses
+        if challenge.status == ChallengeStatus::PendingResult {
+            challenge.transition_to(ChallengeStatus::Finalized, self.signer.key().clone(), challenge_key).unwrap();
+
+            challenge_emit!(
+                ChallengeFinalized {
+                    actor: self.signer.key().clone(),
+                    challenge_key,
+                    id: challenge.id.to_string(),
+                    status: challenge.status,
+                }
+            );
+        }
+
+        // the fees are taken from the prize pool once the result is final
+        let protocol_fee_bps = self.challenge_registry.get_protocol_fee_bps(self.mint_account.key());
+        let fee_amount = challenge.collect_fee(
+            protocol_fee_bps,
+            self.challenge_registry.referral_bps,
+            self.challenge_registry.referral_fee_source,
+        ).unwrap();
+        self.challenge_treasury.balance += fee_amount;
+
+        // the payout remainder is settled against the final prize pool
+        let remainder_amount = challenge.settle_payout_remainder().unwrap();
+        self.challenge_treasury.balance += remainder_amount;
+
+        return Ok(());
+    }
+
     fn claim(&mut self, params: TransferAssetsFromVaultParams) -> Result<()> {
         let current_params = params.clone();
-        let challenge = self.challenge.borrow_mut();
 
         // check whether the challenge is still open for redeeming
-        if !challenge.is_challenge_open_for_claim() {
+        if !self.challenge.is_challenge_open_for_claim() {
             return Err(ChallengeError::ClaimIsNotAvailable.into());
         }
 
         // challenger is not the winner so we raise errors.
-        if !challenge.is_winner(self.signer.key().clone()) {
+        if !self.challenge.is_winner(self.signer.key().clone()) {
             return Err(ChallengeError::ClaimIsNotAvailable.into());
         }
 
-        let reward_amount = challenge.get_prize_for(
-            self.signer.key()
-        ).unwrap();
+        self.finalize_result().unwrap();
+
+        let challenge = self.challenge.borrow_mut();
+        let challenge_key = challenge.key().clone();
 
         // raise error if user already claimed reward
-        if reward_amount == 0 {
+        if challenge.is_reward_claimed_by(self.signer.key()) {
             return Err(ChallengeError::ClaimIsNotAvailable.into());
         }
 
+        let reward_amount = challenge.get_prize_for(
+            self.signer.key()
+        ).unwrap();
+
         // update claim status
         let player = challenge.find_player_for_mutation(self.signer.key()).unwrap();
         player.is_winner_claimed_reward = true;
 
-        // find the bump to sign with the pda
-        let bump = &[challenge.bump][..];
-        let signer = token_account_signer!(
-            PLATFORM_SEED,
-            bump
-        );
-
-        // transfer the token
-        token::transfer(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                Transfer {
-                    from: self.challenge_token_vault.to_account_info(),
-                    to: self.signer_token_account.to_account_info(),
-                    authority: self.challenge_registry.to_account_info(),
-                },
-                signer,
-            ),
-            reward_amount,
-        ).unwrap();
+        // a winner without any share only settles the claim so the challenge can be fully claimed
+        if reward_amount > 0 {
+            // find the bump to sign with the pda
+            let bump = &[self.challenge_registry.bump][..];
+            let signer = token_account_signer!(
+                PLATFORM_SEED,
+                bump
+            );
+
+            // transfer the token
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.challenge_token_vault.to_account_info(),
+                        to: self.signer_token_account.to_account_info(),
+                        authority: self.challenge_registry.to_account_info(),
+                    },
+                    signer,
+                ),
+                reward_amount,
+            ).unwrap();
+        }
 
         // emit event
         challenge_emit!(
@@ -136,7 +212,7 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
         );
 
         if challenge.get_total_unclaimed_winners().unwrap() == 0 {
-            challenge.status = ChallengeStatus::Claimed;
+            challenge.transition_to(ChallengeStatus::Claimed, self.signer.key().clone(), challenge_key).unwrap();
         }
 
         return Ok(());
@@ -145,6 +221,7 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
     fn withdraw(&mut self, params: TransferAssetsFromVaultParams) -> Result<()> {
         let current_params = params.clone();
         let challenge = self.challenge.borrow_mut();
+        let challenge_key = challenge.key().clone();
 
         // check whether the challenge is still open for withdrawal
         if !challenge.is_challenge_open_for_withdrawal() {
@@ -173,7 +250,7 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
         player.is_player_withdrawn = true;
 
         // find the bump to sign with the pda
-        let bump = &[challenge.bump][..];
+        let bump = &[self.challenge_registry.bump][..];
         let signer = token_account_signer!(
             PLATFORM_SEED,
             bump
@@ -206,7 +283,7 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
         );
 
         if challenge.get_total_unwithdrawn_player().unwrap() == 0 {
-            challenge.status = ChallengeStatus::Withdrawn;
+            challenge.transition_to(ChallengeStatus::Withdrawn, self.signer.key().clone(), challenge_key).unwrap();
         }
 
         return Ok(());
@@ -221,6 +298,11 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
         let current_params = params.clone();
         let challenge = self.challenge.borrow_mut();
 
+        // the donations are owed to the winners or the donors once the challenge is over
+        if !challenge.is_challenge_open_for_donate_pool_sweep() {
+            return Err(ChallengeError::WithdrawalIsNotAvailable.into());
+        }
+
         // get withdrawal amount
         let withdrawal_amount = challenge.donate_pool;
 
@@ -230,7 +312,7 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
         }
 
         // find the bump to sign with the pda
-        let bump = &[challenge.bump][..];
+        let bump = &[self.challenge_registry.bump][..];
         let signer = token_account_signer!(
             PLATFORM_SEED,
             bump
@@ -250,9 +332,11 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
             withdrawal_amount,
         ).unwrap();
 
-        // exclude the withdrawn amount
+        // exclude the withdrawn amount, the swept donations cannot be refunded anymore
         challenge.prize_pool -= withdrawal_amount;
         challenge.donate_pool = 0;
+        challenge.donors.iter_mut()
+            .for_each(|donor| donor.total_donation = 0);
 
         // emit event
         challenge_emit!(
@@ -268,4 +352,259 @@ impl<'info> TransferAssetsFromVaultContext<'info> {
 
         return Ok(());
     }
+
+    fn leave(&mut self, params: TransferAssetsFromVaultParams) -> Result<()> {
+        let challenge = self.challenge.borrow_mut();
+
+        // check whether players can still leave the challenge
+        if !challenge.is_challenge_open_for_leaving() {
+            return Err(ChallengeError::LeavingIsNotAvailable.into());
+        }
+
+        // only players can leave the challenge
+        if !challenge.is_player(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyParticipant.into());
+        }
+
+        // the penalty stays in the prize pool
+        let penalty_amount = challenge.get_early_exit_penalty_for(
+            self.signer.key()
+        ).unwrap();
+        let refund_amount = challenge.get_withdrawal_for(
+            self.signer.key()
+        ).unwrap() - penalty_amount;
+
+        // remove the player and exclude the refunded amount
+        challenge.remove_player(self.signer.key()).unwrap();
+        challenge.prize_pool -= refund_amount;
+
+        // find the bump to sign with the pda
+        let bump = &[self.challenge_registry.bump][..];
+        let signer = token_account_signer!(
+            PLATFORM_SEED,
+            bump
+        );
+
+        // transfer the token
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.challenge_token_vault.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                    authority: self.challenge_registry.to_account_info(),
+                },
+                signer,
+            ),
+            refund_amount,
+        ).unwrap();
+
+        // emit event
+        challenge_emit!(
+            RewardClaimed {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                amount: refund_amount,
+                action_type: params.action_type,
+                reward_mint_token: self.mint_account.key().clone(),
+                challenge_id: challenge.id.clone(),
+            }
+        );
+
+        challenge_emit!(
+            PlayerLeft {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                challenge_id: challenge.id.clone(),
+                refund_amount,
+                penalty_amount,
+            }
+        );
+
+        return Ok(());
+    }
+
+    fn refund_donation(&mut self, params: TransferAssetsFromVaultParams) -> Result<()> {
+        let challenge = self.challenge.borrow_mut();
+
+        // check whether the challenge is canceled
+        if !challenge.is_challenge_open_for_donation_refund() {
+            return Err(ChallengeError::DonationRefundIsNotAvailable.into());
+        }
+
+        // only donors can get refunds
+        if !challenge.is_donor(self.signer.key().clone()) {
+            return Err(ChallengeError::DonationRefundIsNotAvailable.into());
+        }
+
+        // get refund amount
+        let refund_amount = challenge.get_donation_refund_for(
+            self.signer.key()
+        ).unwrap();
+
+        // raise error if donor already refunded
+        if refund_amount == 0 || refund_amount > challenge.donate_pool {
+            return Err(ChallengeError::DonationRefundIsNotAvailable.into());
+        }
+
+        // update refund status and exclude the refunded amount
+        let donor = challenge.find_donor_for_mutation(
+            self.signer.key()
+        ).unwrap();
+        donor.is_donation_refunded = true;
+        challenge.prize_pool -= refund_amount;
+        challenge.donate_pool -= refund_amount;
+
+        // find the bump to sign with the pda
+        let bump = &[self.challenge_registry.bump][..];
+        let signer = token_account_signer!(
+            PLATFORM_SEED,
+            bump
+        );
+
+        // transfer the token
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.challenge_token_vault.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                    authority: self.challenge_registry.to_account_info(),
+                },
+                signer,
+            ),
+            refund_amount,
+        ).unwrap();
+
+        // emit event
+        challenge_emit!(
+            RewardClaimed {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                amount: refund_amount,
+                action_type: params.action_type,
+                reward_mint_token: self.mint_account.key().clone(),
+                challenge_id: challenge.id.clone(),
+            }
+        );
+
+        return Ok(());
+    }
+
+    fn claim_creator_fee(&mut self, params: TransferAssetsFromVaultParams) -> Result<()> {
+        // only the challenge owner can claim the creator fee
+        if !self.challenge.is_challenge_owner(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyOwner.into());
+        }
+
+        // the owner can make the result final before any winner claims
+        if self.challenge.is_challenge_open_for_claim() && self.challenge.get_total_winners().unwrap() > 0 {
+            self.finalize_result().unwrap();
+        }
+
+        let challenge = self.challenge.borrow_mut();
+
+        // get the creator fee
+        let fee_amount = challenge.get_claimable_creator_fee().unwrap();
+
+        // raise error if the fee was not taken or already claimed
+        if fee_amount == 0 {
+            return Err(ChallengeError::ClaimIsNotAvailable.into());
+        }
+
+        challenge.is_creator_fee_claimed = true;
+
+        // find the bump to sign with the pda
+        let bump = &[self.challenge_registry.bump][..];
+        let signer = token_account_signer!(
+            PLATFORM_SEED,
+            bump
+        );
+
+        // transfer the token
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.challenge_token_vault.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                    authority: self.challenge_registry.to_account_info(),
+                },
+                signer,
+            ),
+            fee_amount,
+        ).unwrap();
+
+        // emit event
+        challenge_emit!(
+            RewardClaimed {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                amount: fee_amount,
+                action_type: params.action_type,
+                reward_mint_token: self.mint_account.key().clone(),
+                challenge_id: challenge.id.clone(),
+            }
+        );
+
+        return Ok(());
+    }
+    fn claim_referral_reward(&mut self, params: TransferAssetsFromVaultParams) -> Result<()> {
+        // only the referrers can claim the referral reward
+        if !self.challenge.is_referrer(self.signer.key().clone()) {
+            return Err(ChallengeError::ClaimIsNotAvailable.into());
+        }
+
+        // the referrer can make the result final before any winner claims
+        if self.challenge.is_challenge_open_for_claim() && self.challenge.get_total_winners().unwrap() > 0 {
+            self.finalize_result().unwrap();
+        }
+
+        let challenge = self.challenge.borrow_mut();
+
+        // get the referral reward
+        let reward_amount = challenge.get_claimable_referral_reward_for(self.signer.key()).unwrap();
+
+        // raise error if the reward was not taken or already claimed
+        if reward_amount == 0 {
+            return Err(ChallengeError::ClaimIsNotAvailable.into());
+        }
+
+        challenge.find_referrer_for_mutation(self.signer.key()).unwrap().is_referral_reward_claimed = true;
+
+        // find the bump to sign with the pda
+        let bump = &[self.challenge_registry.bump][..];
+        let signer = token_account_signer!(
+            PLATFORM_SEED,
+            bump
+        );
+
+        // transfer the token
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.challenge_token_vault.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                    authority: self.challenge_registry.to_account_info(),
+                },
+                signer,
+            ),
+            reward_amount,
+        ).unwrap();
+
+        // emit event
+        challenge_emit!(
+            RewardClaimed {
+                actor: self.signer.key().clone(),
+                challenge_key: challenge.key().clone(),
+                amount: reward_amount,
+                action_type: params.action_type,
+                reward_mint_token: self.mint_account.key().clone(),
+                challenge_id: challenge.id.clone(),
+            }
+        );
+
+        return Ok(());
+    }
 }
\ No newline at end of file
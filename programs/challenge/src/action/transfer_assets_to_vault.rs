@@ -15,6 +15,9 @@ pub struct TransferAssetsToVaultParams {
     pub challenge_id: String,
     pub action_type: TransferAssetsToVaultActionType,
     pub amount: u64,
+
+    // define who referred the player, only recorded on the first deposit
+    pub referrer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -23,6 +26,9 @@ pub struct TransferAssetsToVaultContext<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        constraint = mint_account.key() == challenge.reward_token_mint_account @ ChallengeError::UnAllowedMintToken,
+    )]
     pub mint_account: Account<'info, Mint>,
 
     #[account(mut)]
@@ -70,15 +76,48 @@ impl<'info> TransferAssetsToVaultContext<'info> {
         let challenge_key = challenge.key().clone();
 
         // check whether the challenge is still open for depositing
-        if !challenge.is_challenge_open_for_participants() {
+        if !challenge.is_challenge_in_progress() {
             return Err(ChallengeError::DepositIsNotAvailable.into());
         }
 
+        // check whether the join deadline has passed
+        if !challenge.is_challenge_open_for_participants() {
+            return Err(ChallengeError::JoiningClosed.into());
+        }
+
         // check whether the amount reaches minimum deposit or not
         if params.amount < challenge.min_deposit {
             return Err(ChallengeError::MinDepositIsNotReached.into());
         }
 
+        // check whether a new player can still join
+        if !challenge.is_player(self.signer.key()) && challenge.is_player_cap_reached() {
+            return Err(ChallengeError::MaxPlayersReached.into());
+        }
+
+        // check whether the player total deposit stays under the cap
+        if challenge.is_deposit_cap_exceeded(self.signer.key(), params.amount) {
+            return Err(ChallengeError::MaxDepositPerPlayerExceeded.into());
+        }
+
+        // check whether the prize pool stays under the cap
+        if challenge.is_prize_pool_cap_exceeded(params.amount) {
+            return Err(ChallengeError::MaxPrizePoolExceeded.into());
+        }
+
+        // players cannot refer themselves
+        if params.referrer == Some(self.signer.key()) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // the referrer is only recorded when the player joins
+        let referrer_index = match params.referrer {
+            Some(referrer) if !challenge.is_player(self.signer.key()) => {
+                Some(challenge.get_or_create_referrer_index(referrer).unwrap())
+            },
+            _ => None,
+        };
+
         // transfer the token
         token::transfer(
             CpiContext::new(
@@ -99,6 +138,21 @@ impl<'info> TransferAssetsToVaultContext<'info> {
         let player = challenge.get_or_create_player(self.signer.key()).unwrap();
         player.total_deposit += params.amount;
 
+        if referrer_index.is_some() {
+            player.referrer_index = referrer_index;
+
+            // emit event
+            challenge_emit!(
+                ReferralRecorded {
+                    actor: self.signer.key().clone(),
+                    challenge_key: challenge_key,
+                    referrer: params.referrer.unwrap(),
+                    challenge_id: challenge.id.clone(),
+                    amount: params.amount,
+                }
+            );
+        }
+
         // emit event
         challenge_emit!(
             RewardReceived {
@@ -119,10 +173,25 @@ impl<'info> TransferAssetsToVaultContext<'info> {
         let challenge_key = challenge.key().clone();
 
         // check whether the challenge is still open for depositing
-        if !challenge.is_challenge_open_for_participants() {
+        if !challenge.is_challenge_in_progress() {
             return Err(ChallengeError::DepositIsNotAvailable.into());
         }
 
+        // check whether the join deadline has passed
+        if !challenge.is_challenge_open_for_participants() {
+            return Err(ChallengeError::JoiningClosed.into());
+        }
+
+        // check whether a new donor can still donate
+        if !challenge.is_donor(self.signer.key()) && challenge.is_donor_cap_reached() {
+            return Err(ChallengeError::MaxDonorsReached.into());
+        }
+
+        // check whether the prize pool stays under the cap
+        if challenge.is_prize_pool_cap_exceeded(params.amount) {
+            return Err(ChallengeError::MaxPrizePoolExceeded.into());
+        }
+
         // transfer the token
         token::transfer(
             CpiContext::new(
@@ -140,6 +209,10 @@ impl<'info> TransferAssetsToVaultContext<'info> {
         challenge.prize_pool += params.amount;
         challenge.donate_pool += params.amount;
 
+        // update donor record
+        let donor = challenge.get_or_create_donor(self.signer.key()).unwrap();
+        donor.total_donation += params.amount;
+
         // emit event
         challenge_emit!(
             RewardReceived {
@@ -0,0 +1,40 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct TransitionChallengeStatusParams {
+    pub challenge_id: String,
+}
+
+// Define the context, passed in parameters when trigger from anyone.
+#[derive(Accounts)]
+#[instruction(params: TransitionChallengeStatusParams)]
+pub struct TransitionChallengeStatusContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge_id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransitionChallengeStatusContext<'info> {
+    pub fn execute(&mut self, _params: TransitionChallengeStatusParams) -> Result<()> {
+        // the stored status must lag behind the scheduled phase
+        if !self.challenge.is_status_transition_available() {
+            return Err(ChallengeError::StatusTransitionIsNotAvailable.into());
+        }
+
+        let to = self.challenge.get_scheduled_status();
+        let challenge_key = self.challenge.key().clone();
+        self.challenge.transition_to(to, self.signer.key().clone(), challenge_key).unwrap();
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct UpdateChallengeParams {
+    // offchain id used as a ref
+    pub id: String,
+
+    // define minimum deposit
+    pub min_deposit: u64,
+
+    // define the mint account for reward
+    pub reward_token_mint_account: Pubkey,
+
+    // define the timestamp after which players can no longer join
+    pub join_deadline: i64,
+
+    // define the timestamp at which the challenge ends
+    pub end_time: i64,
+
+    // define the optional max number of players
+    pub max_players: Option<u64>,
+
+    // define the optional max total deposit of a player
+    pub max_deposit_per_player: Option<u64>,
+
+    // define the optional max prize pool
+    pub max_prize_pool: Option<u64>,
+
+    // define the optional timestamp at which the challenge starts
+    pub start_time: Option<i64>,
+
+    // define the penalty in basis points deducted from the deposit of a leaving player
+    pub early_exit_penalty_bps: u16,
+
+    // define the ascending checkpoint timestamps players must check in before
+    pub milestones: Vec<i64>,
+
+    // define the optional judge recording check-ins on behalf of players
+    pub judge: Option<Pubkey>,
+
+    // define the outcome when the result is final without winners
+    pub no_winner_policy: NoWinnerPolicy,
+
+    // define the challenge receiving the prize pool when rolling over without winners
+    pub no_winner_rollover_target: Option<Pubkey>,
+
+    // define how the prize pool is split between winners
+    pub payout_model: PayoutModel,
+
+    // define the share in basis points of each rank when paying out by rank
+    pub payout_table: Vec<u16>,
+
+    // define who gets the amount left over by the payout shares
+    pub payout_remainder_policy: PayoutRemainderPolicy,
+
+    // define the fee in basis points taken from the prize pool for the challenge owner
+    pub creator_fee_bps: u16,
+}
+
+// Define the context, passed in parameters when trigger from challenge owner.
+#[derive(Accounts)]
+#[instruction(params: UpdateChallengeParams)]
+pub struct UpdateChallengeContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateChallengeContext<'info> {
+    pub fn execute(&mut self, params: UpdateChallengeParams) -> Result<()> {
+        // only the challenge owner can update the challenge
+        if !self.challenge.is_challenge_owner(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyOwner.into());
+        }
+
+        // the challenge can only be updated before anyone joins
+        if !self.challenge.is_challenge_updatable_for(&self.signer.key()) {
+            return Err(ChallengeError::ChallengeCannotBeUpdated.into());
+        }
+
+        // templated challenges can only be updated within their template
+        if self.challenge.template.is_some() {
+            return Err(ChallengeError::TemplateConstraintViolated.into());
+        }
+
+        // set data
+        let challenge = &mut self.challenge;
+        challenge.update_settings(&params).unwrap();
+
+        // Now to validate data state against the registry
+        challenge.validate_with_registry(&self.challenge_registry, None).unwrap();
+
+        challenge_emit!(
+            ChallengeUpdated {
+                id: self.challenge.id.to_string(),
+                challenge_key: self.challenge.key().clone(),
+                actor: self.signer.key().clone()
+            }
+        );
+
+        // ok
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct UpdateChallengeFromTemplateParams {
+    // id of the template the challenge was created from
+    pub template_id: String,
+
+    // define the creator settings, validated against the template
+    pub challenge: UpdateChallengeParams,
+}
+
+// Define the context, passed in parameters when trigger from challenge owner.
+#[derive(Accounts)]
+#[instruction(params: UpdateChallengeFromTemplateParams)]
+pub struct UpdateChallengeFromTemplateContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [TEMPLATE_SEED, params.template_id.as_bytes().as_ref()],
+        bump = challenge_template.bump,
+    )]
+    pub challenge_template: Account<'info, ChallengeTemplate>,
+
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, params.challenge.id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateChallengeFromTemplateContext<'info> {
+    pub fn execute(&mut self, params: UpdateChallengeFromTemplateParams) -> Result<()> {
+        // only the challenge owner can update the challenge
+        if !self.challenge.is_challenge_owner(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyOwner.into());
+        }
+
+        // the challenge can only be updated before anyone joins
+        if !self.challenge.is_challenge_updatable_for(&self.signer.key()) {
+            return Err(ChallengeError::ChallengeCannotBeUpdated.into());
+        }
+
+        // the challenge must be created from the enabled template
+        if self.challenge.template != Some(self.challenge_template.key())
+            || !self.challenge_template.is_enabled {
+            return Err(ChallengeError::TemplateIsNotAvailable.into());
+        }
+
+        // set data, the payout model and fee stay defined by the template
+        let challenge = &mut self.challenge;
+        challenge.update_settings(&params.challenge).unwrap();
+        challenge.payout_model = self.challenge_template.payout_model;
+        challenge.fee_bps = self.challenge_template.fee_bps;
+
+        // players cannot deposit more than the template allows
+        if challenge.max_deposit_per_player.is_none() {
+            challenge.max_deposit_per_player = Some(self.challenge_template.max_deposit);
+        }
+
+        // The creator settings, including the requested mint, must be valid against the registry and within the template
+        self.challenge.validate_with_registry(&self.challenge_registry, None).unwrap();
+        self.challenge_template.validate_challenge(&self.challenge).unwrap();
+
+        challenge_emit!(
+            ChallengeUpdated {
+                id: self.challenge.id.to_string(),
+                challenge_key: self.challenge.key().clone(),
+                actor: self.signer.key().clone()
+            }
+        );
+
+        // ok
+        Ok(())
+    }
+}
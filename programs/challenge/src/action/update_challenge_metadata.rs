@@ -0,0 +1,64 @@
+use crate::*;
+
+// Define params
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct UpdateChallengeMetadataParams {
+    // offchain id used as a ref
+    pub id: String,
+
+    // define the descriptive metadata
+    pub metadata: ChallengeMetadataParams,
+}
+
+// Define the context, passed in parameters when trigger from challenge owner.
+#[derive(Accounts)]
+#[instruction(params: UpdateChallengeMetadataParams)]
+pub struct UpdateChallengeMetadataContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CHALLENGE_SEED, params.id.as_bytes().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, challenge.key().as_ref()],
+        bump = challenge_metadata.bump,
+    )]
+    pub challenge_metadata: Account<'info, ChallengeMetadata>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateChallengeMetadataContext<'info> {
+    pub fn execute(&mut self, params: UpdateChallengeMetadataParams) -> Result<()> {
+        // only the challenge owner can update the metadata
+        if !self.challenge.is_challenge_owner(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyOwner.into());
+        }
+
+        // the metadata can only be updated before the first deposit
+        if !self.challenge.is_challenge_updatable_for(&self.signer.key()) {
+            return Err(ChallengeError::ChallengeCannotBeUpdated.into());
+        }
+
+        // set metadata
+        self.challenge_metadata.set_metadata(params.metadata).unwrap();
+
+        challenge_emit!(
+            ChallengeMetadataUpdated {
+                actor: self.signer.key().clone(),
+                challenge_key: self.challenge.key().clone(),
+                metadata_key: self.challenge_metadata.key().clone(),
+            }
+        );
+
+        // ok
+        Ok(())
+    }
+}
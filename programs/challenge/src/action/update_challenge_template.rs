@@ -0,0 +1,55 @@
+use crate::*;
+
+// Define the context, passed in parameters when trigger from administrators.
+#[derive(Accounts)]
+#[instruction(params: ChallengeTemplateParams)]
+pub struct UpdateChallengeTemplateContext<'info> {
+    // We define the fee payer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TEMPLATE_SEED, params.id.as_bytes().as_ref()],
+        bump = challenge_template.bump,
+    )]
+    pub challenge_template: Account<'info, ChallengeTemplate>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateChallengeTemplateContext<'info> {
+    pub fn execute(&mut self, params: ChallengeTemplateParams) -> Result<()> {
+        // require administrator only
+        if !self.challenge_registry.is_administrator(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // Check if the template offers un-allowed mint tokens
+        if !self.challenge_registry.is_mint_account_enabled(params.reward_token_mint_account) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        // set data, existing challenges keep their settings
+        self.challenge_template.set_template(params).unwrap();
+
+        // emit event
+        challenge_emit!(
+            ChallengeTemplateUpdated {
+                actor: self.signer.key().clone(),
+                template_key: self.challenge_template.key().clone(),
+                id: self.challenge_template.id.clone(),
+                is_enabled: self.challenge_template.is_enabled,
+            }
+        );
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug, PartialEq)]
+pub struct WithdrawFeesParams {
+    pub challenge_token_vault_bump: u8,
+    pub amount: u64,
+}
+
+// Define the context, passed in parameters when trigger from administrators.
+#[derive(Accounts)]
+#[instruction(params: WithdrawFeesParams)]
+pub struct WithdrawFeesContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = challenge_registry.bump,
+    )]
+    pub challenge_registry: Account<'info, ChallengePlatformRegistry>,
+
+    #[account(mut)]
+    /// CHECK: the recipient token account can be verified later
+    pub signer_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_ACCOUNT_SEED, mint_account.key().as_ref()],
+        bump = params.challenge_token_vault_bump
+    )]
+    pub challenge_token_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, mint_account.key().as_ref()],
+        bump = challenge_treasury.bump
+    )]
+    pub challenge_treasury: Account<'info, ChallengeTreasury>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawFeesContext<'info> {
+    pub fn execute(&mut self, params: WithdrawFeesParams) -> Result<()> {
+        // require administrator only
+        if !self.challenge_registry.is_administrator(self.signer.key().clone()) {
+            return Err(ChallengeError::OnlyAdministrator.into());
+        }
+
+        // only the accrued funds can be withdrawn
+        if params.amount == 0 || params.amount > self.challenge_treasury.balance {
+            return Err(ChallengeError::WithdrawalIsNotAvailable.into());
+        }
+
+        self.challenge_treasury.balance -= params.amount;
+
+        // find the bump to sign with the pda
+        let bump = &[self.challenge_registry.bump][..];
+        let signer = token_account_signer!(
+            PLATFORM_SEED,
+            bump
+        );
+
+        // transfer the token
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.challenge_token_vault.to_account_info(),
+                    to: self.signer_token_account.to_account_info(),
+                    authority: self.challenge_registry.to_account_info(),
+                },
+                signer,
+            ),
+            params.amount,
+        ).unwrap();
+
+        // emit event
+        challenge_emit!(
+            FeesWithdrawn {
+                actor: self.signer.key().clone(),
+                treasury_key: self.challenge_treasury.key().clone(),
+                mint_account: self.mint_account.key().clone(),
+                recipient_token_account: self.signer_token_account.key().clone(),
+                amount: params.amount,
+            }
+        );
+
+        return Ok(());
+    }
+}
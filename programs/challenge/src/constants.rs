@@ -3,3 +3,37 @@
 pub const PLATFORM_SEED: &[u8] = b"SEED::CHALLENGE::PLATFORM";
 pub const TOKEN_ACCOUNT_SEED: &[u8] = b"SEED::CHALLENGE::TOKEN_VAULT";
 pub const CHALLENGE_SEED: &[u8] = b"SEED::CHALLENGE";
+pub const DISPUTE_SEED: &[u8] = b"SEED::CHALLENGE::DISPUTE";
+pub const METADATA_SEED: &[u8] = b"SEED::CHALLENGE::METADATA";
+pub const SERIES_SEED: &[u8] = b"SEED::CHALLENGE::SERIES";
+pub const SUBMISSION_SEED: &[u8] = b"SEED::CHALLENGE::SUBMISSION";
+pub const TREASURY_SEED: &[u8] = b"SEED::CHALLENGE::TREASURY";
+pub const TEMPLATE_SEED: &[u8] = b"SEED::CHALLENGE::TEMPLATE";
+
+// Define max length of uri fields
+pub const MAX_URI_LENGTH: usize = 200;
+
+// Define max length of metadata text fields
+pub const MAX_TITLE_LENGTH: usize = 64;
+pub const MAX_CATEGORY_LENGTH: usize = 32;
+
+// Define max length of series id, leaving room for the round suffix within the 32 bytes seed limit
+pub const MAX_SERIES_ID_LENGTH: usize = 20;
+
+// Define max length of template id within the 32 bytes seed limit
+pub const MAX_TEMPLATE_ID_LENGTH: usize = 32;
+
+// Define the account space of a challenge, which bounds the player list
+pub const CHALLENGE_ACCOUNT_SPACE: usize = 10240;
+pub const MAX_PLAYERS_PER_CHALLENGE: u64 = 100;
+pub const MAX_DONORS_PER_CHALLENGE: u64 = 50;
+pub const MAX_REFERRERS_PER_CHALLENGE: u64 = 20;
+
+// Define max number of milestones, bounded by the check-in bitmask of a player
+pub const MAX_MILESTONES_PER_CHALLENGE: usize = 32;
+
+// Define max number of ranks in a payout table
+pub const MAX_PAYOUT_RANKS: usize = 10;
+
+// Define the denominator of basis points
+pub const BPS_DENOMINATOR: u64 = 10000;
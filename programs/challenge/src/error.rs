@@ -35,4 +35,54 @@ pub enum ChallengeError {
     AlreadyParticipated,
     #[msg("Min deposit amount is not reached")]
     MinDepositIsNotReached,
+    #[msg("Joining is closed for the challenge")]
+    JoiningClosed,
+    #[msg("The challenge has not ended yet")]
+    ChallengeNotEndedYet,
+    #[msg("Challenge cannot be expired")]
+    ChallengeCannotBeExpired,
+    #[msg("Dispute is not available for the challenge")]
+    DisputeIsNotAvailable,
+    #[msg("The dispute was already resolved")]
+    DisputeAlreadyResolved,
+    #[msg("Max players of the challenge is reached")]
+    MaxPlayersReached,
+    #[msg("Max deposit per player is exceeded")]
+    MaxDepositPerPlayerExceeded,
+    #[msg("Max prize pool of the challenge is exceeded")]
+    MaxPrizePoolExceeded,
+    #[msg("Challenge cannot be updated")]
+    ChallengeCannotBeUpdated,
+    #[msg("Leaving is not available for the challenge")]
+    LeavingIsNotAvailable,
+    #[msg("Rollover is not available for the challenge")]
+    RolloverIsNotAvailable,
+    #[msg("Check-in is not available for the milestone")]
+    CheckInIsNotAvailable,
+    #[msg("The player was eliminated from the challenge")]
+    PlayerEliminated,
+    #[msg("Proof submission is not available for the challenge")]
+    SubmissionIsNotAvailable,
+    #[msg("The submission was already reviewed")]
+    SubmissionAlreadyReviewed,
+    #[msg("No-winner policy is not available for the challenge")]
+    NoWinnerPolicyIsNotAvailable,
+    #[msg("The winner list cannot be amended")]
+    WinnerListCannotBeAmended,
+    #[msg("The challenge template is not available")]
+    TemplateIsNotAvailable,
+    #[msg("The challenge settings violate the template")]
+    TemplateConstraintViolated,
+    #[msg("The challenge status is already up to date")]
+    StatusTransitionIsNotAvailable,
+    #[msg("Max donors of the challenge is reached")]
+    MaxDonorsReached,
+    #[msg("Donation refund is not available")]
+    DonationRefundIsNotAvailable,
+    #[msg("The challenge still has outstanding liabilities")]
+    ChallengeCannotBeClosed,
+    #[msg("The challenge status transition is not allowed")]
+    StatusTransitionIsNotAllowed,
+    #[msg("Max referrers of the challenge is reached")]
+    MaxReferrersReached,
 }
@@ -19,6 +19,20 @@ pub struct ChallengeRegistryUpdated {
     pub allowed_administrators: Vec<Pubkey>,
     // define whitelisted mint token account
     pub allowed_mint_accounts: Vec<MintInfo>,
+    // define the period after the challenge end before anyone can expire it
+    pub expiry_grace_period: i64,
+    // define the period after winner submission during which participants can dispute
+    pub dispute_window: i64,
+    // define the period after winner submission during which the winner list can be amended
+    pub correction_window: i64,
+    // define the protocol fee in basis points taken from the prize pool once the result is final
+    pub protocol_fee_bps: u16,
+    // define the max creator fee in basis points a challenge owner can set
+    pub max_creator_fee_bps: u16,
+    // define the share in basis points of the referral fee source paid out to referrers
+    pub referral_bps: u16,
+    // define which fee pays the referral rewards
+    pub referral_fee_source: ReferralFeeSource,
 }
 
 
@@ -46,6 +60,65 @@ pub struct ChallengeCreated {
     pub id: String,
 }
 
+/// Emitted when a [ChallengeUpdated] is created.
+#[event]
+pub struct ChallengeUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub id: String,
+}
+
+/// Emitted when a [ChallengeMetadataUpdated] is created.
+#[event]
+pub struct ChallengeMetadataUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub metadata_key: Pubkey,
+}
+
+/// Emitted when a [WinnerListSubmitted] is created.
+#[event]
+pub struct WinnerListSubmitted {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub id: String,
+    pub winner_list: Vec<Pubkey>,
+    pub dispute_deadline: i64,
+}
+
+/// Emitted when a [DisputeOpened] is created.
+#[event]
+pub struct DisputeOpened {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub dispute_key: Pubkey,
+    pub reason_uri: String,
+}
+
+/// Emitted when a [DisputeResolved] is created.
+#[event]
+pub struct DisputeResolved {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub dispute_key: Pubkey,
+    pub status: DisputeStatus,
+    pub winner_list: Vec<Pubkey>,
+}
 
 /// Emitted when a [ChallengeFinalized] is created.
 #[event]
@@ -71,6 +144,18 @@ pub struct ChallengeCanceled {
     pub status: ChallengeStatus,
 }
 
+/// Emitted when a [ChallengeExpired] is created.
+#[event]
+pub struct ChallengeExpired {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub id: String,
+    pub status: ChallengeStatus,
+}
+
 /// Emitted when a [RewardReceived] is created.
 #[event]
 pub struct RewardReceived {
@@ -100,3 +185,240 @@ pub struct RewardClaimed {
     pub action_type: TransferAssetsFromVaultActionType,
     pub amount: u64,
 }
+
+/// Emitted when a [PlayerLeft] is created.
+#[event]
+pub struct PlayerLeft {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub challenge_id: String,
+    pub refund_amount: u64,
+    pub penalty_amount: u64,
+}
+
+/// Emitted when a [ChallengeSeriesCreated] is created.
+#[event]
+pub struct ChallengeSeriesCreated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub series_key: Pubkey,
+    #[index]
+    pub id: String,
+}
+
+/// Emitted when a [SeriesRoundSpawned] is created.
+#[event]
+pub struct SeriesRoundSpawned {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub series_key: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    pub round: u64,
+}
+
+/// Emitted when a [PrizePoolRolledOver] is created.
+#[event]
+pub struct PrizePoolRolledOver {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub source_challenge_key: Pubkey,
+    #[index]
+    pub target_challenge_key: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a [CheckInRecorded] is created.
+#[event]
+pub struct CheckInRecorded {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub player: Pubkey,
+    pub milestone_index: u8,
+}
+
+/// Emitted when a [PlayerEliminated] is created.
+#[event]
+pub struct PlayerEliminated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub player: Pubkey,
+}
+
+/// Emitted when a [ProofSubmitted] is created.
+#[event]
+pub struct ProofSubmitted {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub submission_key: Pubkey,
+    pub evidence_uri: String,
+    pub content_hash: [u8; 32],
+}
+
+/// Emitted when a [ProofReviewed] is created.
+#[event]
+pub struct ProofReviewed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub submission_key: Pubkey,
+    pub player: Pubkey,
+    pub status: SubmissionStatus,
+}
+
+/// Emitted when a [NoWinnerPolicyApplied] is created.
+#[event]
+pub struct NoWinnerPolicyApplied {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub policy: NoWinnerPolicy,
+    pub amount: u64,
+}
+
+/// Emitted when a [WinnerListAmended] is created.
+#[event]
+pub struct WinnerListAmended {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub id: String,
+    pub previous_winner_list: Vec<Pubkey>,
+    pub winner_list: Vec<Pubkey>,
+}
+
+/// Emitted when a [ChallengeTemplateUpdated] is created.
+#[event]
+pub struct ChallengeTemplateUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub template_key: Pubkey,
+    #[index]
+    pub id: String,
+    pub is_enabled: bool,
+}
+
+/// Emitted when a [ChallengeStatusChanged] is created.
+#[event]
+pub struct ChallengeStatusChanged {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub id: String,
+    pub from: ChallengeStatus,
+    pub to: ChallengeStatus,
+}
+
+/// Emitted when a [ChallengeClosed] is created.
+#[event]
+pub struct ChallengeClosed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub id: String,
+    pub owner: Pubkey,
+    pub status: ChallengeStatus,
+    pub reward_token_mint_account: Pubkey,
+    pub total_players: u64,
+    pub total_winners: u64,
+    pub total_deposit: u64,
+    pub prize_pool: u64,
+    pub collected_fee: u64,
+    pub reclaimed_lamports: u64,
+}
+
+/// Emitted when a [MintProtocolFeeUpdated] is created.
+#[event]
+pub struct MintProtocolFeeUpdated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub mint_account: Pubkey,
+    pub protocol_fee_bps: Option<u16>,
+}
+
+/// Emitted when a [DisputeClosed] is created.
+#[event]
+pub struct DisputeClosed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub dispute_key: Pubkey,
+    pub disputer: Pubkey,
+}
+
+/// Emitted when a [SubmissionClosed] is created.
+#[event]
+pub struct SubmissionClosed {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub submission_key: Pubkey,
+    pub player: Pubkey,
+}
+
+/// Emitted when a [FeesWithdrawn] is created.
+#[event]
+pub struct FeesWithdrawn {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub treasury_key: Pubkey,
+    pub mint_account: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a [TreasuryCreated] is created.
+#[event]
+pub struct TreasuryCreated {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub treasury_key: Pubkey,
+    #[index]
+    pub mint_account: Pubkey,
+}
+
+/// Emitted when a [ReferralRecorded] is created.
+#[event]
+pub struct ReferralRecorded {
+    #[index]
+    pub actor: Pubkey,
+    #[index]
+    pub challenge_key: Pubkey,
+    #[index]
+    pub referrer: Pubkey,
+    pub challenge_id: String,
+    pub amount: u64,
+}
\ No newline at end of file
@@ -57,6 +57,7 @@ pub mod challenge {
     ) -> Result<()> {
         ctx.accounts.execute(
             *ctx.bumps.get("challenge_token_vault").unwrap(),
+            *ctx.bumps.get("challenge_treasury").unwrap(),
         ).unwrap();
 
         Ok(())
@@ -70,11 +71,32 @@ pub mod challenge {
         ctx.accounts.execute(
             params,
             *ctx.bumps.get("challenge").unwrap(),
+            *ctx.bumps.get("challenge_metadata").unwrap(),
         ).unwrap();
 
         Ok(())
     }
 
+    // Update challenge before anyone joins, challenge owner only
+    pub fn update_challenge(
+        ctx: Context<UpdateChallengeContext>,
+        params: UpdateChallengeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Update challenge metadata before anyone joins, challenge owner only
+    pub fn update_challenge_metadata(
+        ctx: Context<UpdateChallengeMetadataContext>,
+        params: UpdateChallengeMetadataParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
     // Create challenge, public to anyone
     pub fn cancel_challenge(
         ctx: Context<CancelChallengeContext>,
@@ -113,4 +135,257 @@ pub mod challenge {
 
         Ok(())
     }
+
+    // Expire an abandoned challenge, public to anyone
+    pub fn expire_challenge(
+        ctx: Context<ExpireChallengeContext>,
+        params: ExpireChallengeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Dispute the submitted winner list, participants only
+    pub fn open_dispute(
+        ctx: Context<OpenDisputeContext>,
+        params: OpenDisputeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("dispute").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
+
+    // Resolve the dispute, administrators only
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDisputeContext>,
+        params: ResolveDisputeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Create challenge series, public to anyone
+    pub fn create_challenge_series(
+        ctx: Context<CreateChallengeSeriesContext>,
+        params: CreateChallengeSeriesParams
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("challenge_series").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
+
+    // Spawn the next round of a series, series owner only
+    pub fn spawn_series_round(
+        ctx: Context<SpawnSeriesRoundContext>,
+        params: SpawnSeriesRoundParams
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("challenge").unwrap(),
+            *ctx.bumps.get("challenge_metadata").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
+
+    // Record a milestone check-in, players or the judge only
+    pub fn record_check_in(
+        ctx: Context<RecordCheckInContext>,
+        params: RecordCheckInParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Settle a milestone challenge with its survivors, public to anyone
+    pub fn settle_milestone_challenge(
+        ctx: Context<SettleMilestoneChallengeContext>,
+        params: SettleMilestoneChallengeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Submit a proof of the challenge, participants only
+    pub fn submit_proof(
+        ctx: Context<SubmitProofContext>,
+        params: SubmitProofParams
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("submission").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
+
+    // Accept or reject a submitted proof, administrators or challenge owner only
+    pub fn review_proof(
+        ctx: Context<ReviewProofContext>,
+        params: ReviewProofParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Refund players or fund the treasury when there is no winner, public to anyone
+    pub fn apply_no_winner_policy(
+        ctx: Context<ApplyNoWinnerPolicyContext>,
+        params: ApplyNoWinnerPolicyParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Roll the prize pool over into the designated challenge when there is no winner, public to anyone
+    pub fn apply_no_winner_rollover(
+        ctx: Context<ApplyNoWinnerRolloverContext>,
+        params: ApplyNoWinnerRolloverParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Amend the winner list before the first claim, administrators or challenge owner only
+    pub fn amend_winner_list(
+        ctx: Context<AmendWinnerListContext>,
+        params: AmendWinnerListParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Create challenge template, administrators only
+    pub fn create_challenge_template(
+        ctx: Context<CreateChallengeTemplateContext>,
+        params: ChallengeTemplateParams
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("challenge_template").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
+
+    // Update challenge template, administrators only
+    pub fn update_challenge_template(
+        ctx: Context<UpdateChallengeTemplateContext>,
+        params: ChallengeTemplateParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Create challenge from a template, public to anyone
+    pub fn create_challenge_from_template(
+        ctx: Context<CreateChallengeFromTemplateContext>,
+        params: CreateChallengeFromTemplateParams
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            params,
+            *ctx.bumps.get("challenge").unwrap(),
+            *ctx.bumps.get("challenge_metadata").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
+
+    // Update a templated challenge within its template before anyone joins, challenge owner only
+    pub fn update_challenge_from_template(
+        ctx: Context<UpdateChallengeFromTemplateContext>,
+        params: UpdateChallengeFromTemplateParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Move the challenge into its scheduled phase, public to anyone
+    pub fn transition_challenge_status(
+        ctx: Context<TransitionChallengeStatusContext>,
+        params: TransitionChallengeStatusParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Cancel the challenge in an emergency, administrators only
+    pub fn force_cancel_challenge(
+        ctx: Context<ForceCancelChallengeContext>,
+        params: ForceCancelChallengeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Close a settled challenge and return the rent to the challenge owner
+    pub fn close_challenge(
+        ctx: Context<CloseChallengeContext>,
+        params: CloseChallengeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+    // Withdraw the fees accrued in the treasury, administrators only
+    pub fn withdraw_fees(
+        ctx: Context<WithdrawFeesContext>,
+        params: WithdrawFeesParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Override the protocol fee of a mint, administrators only
+    pub fn set_mint_protocol_fee(
+        ctx: Context<SetMintProtocolFeeContext>,
+        params: SetMintProtocolFeeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Close the dispute of a settled challenge and return the rent to the disputer, public to anyone
+    pub fn close_dispute(
+        ctx: Context<CloseDisputeContext>,
+        params: CloseDisputeParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Close a proof submission of a settled challenge and return the rent to the player, public to anyone
+    pub fn close_submission(
+        ctx: Context<CloseSubmissionContext>,
+        params: CloseSubmissionParams
+    ) -> Result<()> {
+        ctx.accounts.execute(params).unwrap();
+
+        Ok(())
+    }
+
+    // Create the treasury of a mint whitelisted before treasuries existed, administrators only
+    pub fn create_treasury(
+        ctx: Context<CreateTreasuryContext>
+    ) -> Result<()> {
+        ctx.accounts.execute(
+            *ctx.bumps.get("challenge_treasury").unwrap(),
+        ).unwrap();
+
+        Ok(())
+    }
 }
\ No newline at end of file
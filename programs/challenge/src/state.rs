@@ -12,6 +12,13 @@ pub struct MintInfo {
     pub token_account: Pubkey,
 }
 
+// Here we define the protocol fee charged for a mint instead of the registry fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct MintProtocolFee {
+    pub mint_account: Pubkey,
+    pub protocol_fee_bps: u16,
+}
+
 #[account]
 #[derive(Default)]
 pub struct ChallengePlatformRegistry {
@@ -29,6 +36,30 @@ pub struct ChallengePlatformRegistry {
 
     // define whitelisted mint token account
     pub allowed_mint_accounts: Vec<MintInfo>,
+
+    // Define the period after the challenge end before anyone can expire it
+    pub expiry_grace_period: i64,
+
+    // Define the period after winner submission during which participants can dispute
+    pub dispute_window: i64,
+
+    // Define the period after winner submission during which the winner list can be amended
+    pub correction_window: i64,
+
+    // Define the protocol fee in basis points taken from the prize pool once the result is final
+    pub protocol_fee_bps: u16,
+
+    // Define the max creator fee in basis points a challenge owner can set
+    pub max_creator_fee_bps: u16,
+
+    // Define the share in basis points of the referral fee source paid out to referrers
+    pub referral_bps: u16,
+
+    // Define which fee pays the referral rewards
+    pub referral_fee_source: ReferralFeeSource,
+
+    // Define the protocol fee overrides of the mints
+    pub mint_protocol_fees: Vec<MintProtocolFee>,
 }
 
 // Define handler
@@ -70,6 +101,44 @@ impl ChallengePlatformRegistry {
             .borrow();
     }
 
+    // Define the protocol fee of a mint, falling back to the registry fee
+    pub fn get_protocol_fee_bps(&self, mint_account: Pubkey) -> u16 {
+        return self.mint_protocol_fees.iter()
+            .find(|mint_protocol_fee| mint_protocol_fee.mint_account == mint_account)
+            .map(|mint_protocol_fee| mint_protocol_fee.protocol_fee_bps)
+            .unwrap_or(self.protocol_fee_bps);
+    }
+
+    // Override the protocol fee of a mint, none falls back to the registry fee
+    pub fn set_mint_protocol_fee(&mut self, mint_account: Pubkey, protocol_fee_bps: Option<u16>) -> Result<()> {
+        self.mint_protocol_fees.retain(|mint_protocol_fee| mint_protocol_fee.mint_account != mint_account);
+
+        if let Some(protocol_fee_bps) = protocol_fee_bps {
+            self.mint_protocol_fees.push(MintProtocolFee {
+                mint_account,
+                protocol_fee_bps,
+            });
+        }
+
+        return Ok(());
+    }
+
+    // Define whether the fees stay within the prize pool
+    pub fn is_fee_settings_valid(&self) -> bool {
+        return self.protocol_fee_bps as u64 <= BPS_DENOMINATOR
+            && self.max_creator_fee_bps as u64 <= BPS_DENOMINATOR
+            && self.referral_bps as u64 <= BPS_DENOMINATOR
+            && self.mint_protocol_fees.iter()
+                .all(|mint_protocol_fee| mint_protocol_fee.protocol_fee_bps as u64 <= BPS_DENOMINATOR);
+    }
+
+    // Define whether the time windows are not negative
+    pub fn is_window_settings_valid(&self) -> bool {
+        return self.expiry_grace_period >= 0
+            && self.dispute_window >= 0
+            && self.correction_window >= 0;
+    }
+
     // Define whether the signer is a winner
     pub fn is_administrator(&self, signer: Pubkey) -> bool {
         return self.owner.clone().key() == signer ||
@@ -83,9 +152,9 @@ impl ChallengePlatformRegistry {
 // ================ Swap Option Interface ================ //
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
 pub enum ChallengeStatus {
-    // Declare that the challenge is created
+    // Declare that the challenge is open for joining
     #[default]
-    Created,
+    Open,
 
     // Declare that the challenge is finalized
     Finalized,
@@ -98,6 +167,118 @@ pub enum ChallengeStatus {
 
     // Declare that the challenge is withdrawn.
     Withdrawn,
+
+    // Declare that the winner list is submitted and waiting for the dispute window to lapse.
+    PendingResult,
+
+    // Declare that the winner list is disputed and waiting for administrators.
+    Disputed,
+
+    // Declare that joining is closed and the challenge is waiting for its start time.
+    Upcoming,
+
+    // Declare that the challenge has started.
+    Running,
+}
+
+// Implement the challenge lifecycle
+impl ChallengeStatus {
+    // Define the statuses reachable from the current status, every status must be listed here.
+    pub fn get_allowed_transitions(&self) -> Vec<ChallengeStatus> {
+        return match self {
+            ChallengeStatus::Open => vec![
+                ChallengeStatus::Upcoming,
+                ChallengeStatus::Running,
+                ChallengeStatus::PendingResult,
+                ChallengeStatus::Canceled,
+            ],
+            ChallengeStatus::Upcoming => vec![
+                ChallengeStatus::Running,
+                ChallengeStatus::PendingResult,
+                ChallengeStatus::Canceled,
+            ],
+            ChallengeStatus::Running => vec![
+                ChallengeStatus::PendingResult,
+                ChallengeStatus::Canceled,
+            ],
+            ChallengeStatus::PendingResult => vec![
+                ChallengeStatus::Disputed,
+                ChallengeStatus::Finalized,
+                ChallengeStatus::Claimed,
+                ChallengeStatus::Canceled,
+            ],
+            ChallengeStatus::Disputed => vec![
+                ChallengeStatus::Finalized,
+                ChallengeStatus::Canceled,
+            ],
+            ChallengeStatus::Finalized => vec![
+                ChallengeStatus::Claimed,
+                ChallengeStatus::Canceled,
+            ],
+            ChallengeStatus::Canceled => vec![
+                ChallengeStatus::Withdrawn,
+            ],
+            ChallengeStatus::Claimed => vec![],
+            ChallengeStatus::Withdrawn => vec![],
+        };
+    }
+
+    // Define whether the status can move to another status.
+    pub fn can_transition_to(&self, to: ChallengeStatus) -> bool {
+        return self.get_allowed_transitions().contains(&to);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum NoWinnerPolicy {
+    // Declare that players can withdraw their deposits
+    #[default]
+    RefundPlayers,
+
+    // Declare that the prize pool goes to the registry treasury
+    SendToTreasury,
+
+    // Declare that the prize pool rolls over into the designated challenge
+    RollOver,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum PayoutModel {
+    // Declare that winners share the prize pool equally
+    #[default]
+    EqualSplit,
+
+    // Declare that winners share the prize pool by their rank following the payout table
+    Ranked,
+
+    // Declare that winners share the prize pool in proportion to their deposits
+    StakeProportional,
+
+    // Declare that winners get their deposits back and share the forfeited deposits and donations equally
+    StakeBack,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum PayoutRemainderPolicy {
+    // Declare that the last winner claiming the reward gets the remainder
+    #[default]
+    LastClaimer,
+
+    // Declare that the top-ranked winner gets the remainder
+    TopRankedWinner,
+
+    // Declare that the remainder goes to the registry treasury
+    Treasury,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum ReferralFeeSource {
+    // Declare that the referral rewards are taken from the fee collected into the treasury
+    #[default]
+    ProtocolFee,
+
+    // Declare that the referral rewards are taken from the creator fee
+    CreatorFee,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
@@ -107,6 +288,32 @@ pub struct PlayerInfo {
     pub is_winner: bool,
     pub is_winner_claimed_reward: bool,
     pub is_player_withdrawn: bool,
+
+    // bitmask of the milestones the player checked in
+    pub checked_in_milestones: u32,
+
+    // whether the player missed a milestone
+    pub is_eliminated: bool,
+
+    // position of the winner in the submitted ranking, starting from 1
+    pub rank: u8,
+
+    // position of the referrer in the referrer list of the challenge
+    pub referrer_index: Option<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct DonorInfo {
+    pub public_key: Pubkey,
+    pub total_donation: u64,
+    pub is_donation_refunded: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct ReferrerInfo {
+    pub public_key: Pubkey,
+    pub referral_reward: u64,
+    pub is_referral_reward_claimed: bool,
 }
 
 // Here we define the account state that holds the swap order. SwapOrder will be the PDA.
@@ -139,6 +346,111 @@ pub struct Challenge {
 
     // Define the challenge status
     pub status: ChallengeStatus,
+
+    // Define the timestamp after which players can no longer join
+    pub join_deadline: i64,
+
+    // Define the timestamp at which the challenge ends
+    pub end_time: i64,
+
+    // Define the timestamp until which the submitted winner list can be disputed
+    pub dispute_deadline: i64,
+
+    // Define the timestamp the winner list was submitted
+    pub result_submitted_at: i64,
+
+    // Define the optional max number of players
+    pub max_players: Option<u64>,
+
+    // Define the optional max total deposit of a player
+    pub max_deposit_per_player: Option<u64>,
+
+    // Define the optional max prize pool
+    pub max_prize_pool: Option<u64>,
+
+    // Define the optional timestamp at which the challenge starts
+    pub start_time: Option<i64>,
+
+    // Define the penalty in basis points deducted from the deposit of a leaving player
+    pub early_exit_penalty_bps: u16,
+
+    // Define the series the challenge belongs to
+    pub series: Option<Pubkey>,
+
+    // Define the round of the challenge in its series
+    pub round: u64,
+
+    // Define the ascending checkpoint timestamps players must check in before
+    pub milestones: Vec<i64>,
+
+    // Define the optional judge recording check-ins on behalf of players
+    pub judge: Option<Pubkey>,
+
+    // Define the outcome when the result is final without winners
+    pub no_winner_policy: NoWinnerPolicy,
+
+    // Define the challenge receiving the prize pool when rolling over without winners
+    pub no_winner_rollover_target: Option<Pubkey>,
+
+    // Define how the prize pool is split between winners
+    pub payout_model: PayoutModel,
+
+    // Define the fee in basis points taken from the prize pool into the treasury once the result is final
+    pub fee_bps: u16,
+
+    // Define the taken fee
+    pub collected_fee: u64,
+
+    // Define whether the fee was taken
+    pub is_fee_collected: bool,
+
+    // Define the template the challenge was created from
+    pub template: Option<Pubkey>,
+
+    // Define the reason code of an administrator cancellation
+    pub cancel_reason_code: u16,
+
+    // Define the uri describing the cancellation reason
+    pub cancel_reason_uri: String,
+
+    // Define the donor list
+    pub donors: Vec<DonorInfo>,
+
+    // Define the share in basis points of each rank when paying out by rank
+    pub payout_table: Vec<u16>,
+
+    // Define who gets the amount left over by the payout shares
+    pub payout_remainder_policy: PayoutRemainderPolicy,
+
+    // Define the amount left over by the payout shares
+    pub payout_remainder: u64,
+
+    // Define whether the payout remainder was settled
+    pub is_payout_remainder_settled: bool,
+
+    // Define the fee in basis points taken from the prize pool for the challenge owner once the result is final
+    pub creator_fee_bps: u16,
+
+    // Define the taken creator fee
+    pub creator_fee: u64,
+
+    // Define whether the challenge owner claimed the creator fee
+    pub is_creator_fee_claimed: bool,
+
+    // Define the referrers of the players
+    pub referrers: Vec<ReferrerInfo>,
+
+    // Define whether administrators resolved a dispute against the winner list
+    pub is_dispute_resolved: bool,
+
+    // Define the prize pool rolled over from challenges without winners, nobody can refund it
+    pub rollover_pool: u64,
+
+    // Define the number of proof submissions which are not closed yet
+    pub total_submissions: u64,
+
+    // Define whether the dispute of the challenge is not closed yet
+    pub is_dispute_opened: bool,
 }
 
 // Implement some domain logic
@@ -149,23 +461,148 @@ impl Challenge {
             bump: 0,
             id: String::default(),
             owner: Pubkey::default(),
-            status: ChallengeStatus::Created,
+            status: ChallengeStatus::Open,
             players: vec![],
             reward_token_mint_account: Pubkey::default(),
             min_deposit: 0,
             prize_pool: 0,
-            donate_pool: 0
+            donate_pool: 0,
+            join_deadline: 0,
+            end_time: 0,
+            dispute_deadline: 0,
+            result_submitted_at: 0,
+            max_players: None,
+            max_deposit_per_player: None,
+            max_prize_pool: None,
+            start_time: None,
+            early_exit_penalty_bps: 0,
+            series: None,
+            round: 0,
+            milestones: vec![],
+            judge: None,
+            no_winner_policy: NoWinnerPolicy::RefundPlayers,
+            no_winner_rollover_target: None,
+            payout_model: PayoutModel::EqualSplit,
+            fee_bps: 0,
+            collected_fee: 0,
+            is_fee_collected: false,
+            template: None,
+            cancel_reason_code: 0,
+            cancel_reason_uri: String::default(),
+            donors: vec![],
+            payout_table: vec![],
+            payout_remainder_policy: PayoutRemainderPolicy::LastClaimer,
+            payout_remainder: 0,
+            is_payout_remainder_settled: false,
+            creator_fee_bps: 0,
+            creator_fee: 0,
+            is_creator_fee_claimed: false,
+            referrers: vec![],
+            is_dispute_resolved: false,
+            rollover_pool: 0,
+            total_submissions: 0,
+            is_dispute_opened: false,
+        }
+    }
+
+    // assign the settings defined by the creator
+    pub fn set_settings(&mut self, params: &CreateChallengeParams) -> Result<()> {
+        self.id = params.id.clone();
+        self.min_deposit = params.min_deposit;
+        self.reward_token_mint_account = params.reward_token_mint_account;
+        self.join_deadline = params.join_deadline;
+        self.end_time = params.end_time;
+        self.max_players = params.max_players;
+        self.max_deposit_per_player = params.max_deposit_per_player;
+        self.max_prize_pool = params.max_prize_pool;
+        self.start_time = params.start_time;
+        self.early_exit_penalty_bps = params.early_exit_penalty_bps;
+        self.milestones = params.milestones.clone();
+        self.judge = params.judge;
+        self.no_winner_policy = params.no_winner_policy;
+        self.no_winner_rollover_target = params.no_winner_rollover_target;
+        self.payout_model = params.payout_model;
+        self.payout_table = params.payout_table.clone();
+        self.payout_remainder_policy = params.payout_remainder_policy;
+        self.creator_fee_bps = params.creator_fee_bps;
+
+        return Ok(());
+    }
+
+    // Update the configurable settings of the challenge
+    pub fn update_settings(&mut self, params: &UpdateChallengeParams) -> Result<()> {
+        self.min_deposit = params.min_deposit;
+        self.reward_token_mint_account = params.reward_token_mint_account;
+        self.join_deadline = params.join_deadline;
+        self.end_time = params.end_time;
+        self.max_players = params.max_players;
+        self.max_deposit_per_player = params.max_deposit_per_player;
+        self.max_prize_pool = params.max_prize_pool;
+        self.start_time = params.start_time;
+        self.early_exit_penalty_bps = params.early_exit_penalty_bps;
+        self.milestones = params.milestones.clone();
+        self.judge = params.judge;
+        self.no_winner_policy = params.no_winner_policy;
+        self.no_winner_rollover_target = params.no_winner_rollover_target;
+        self.payout_model = params.payout_model;
+        self.payout_table = params.payout_table.clone();
+        self.payout_remainder_policy = params.payout_remainder_policy;
+        self.creator_fee_bps = params.creator_fee_bps;
+
+        return Ok(());
+    }
+
+    // Define the state that the challenge has no result yet.
+    pub fn is_challenge_in_progress(&self) -> bool {
+        return self.status == ChallengeStatus::Open
+            || self.status == ChallengeStatus::Upcoming
+            || self.status == ChallengeStatus::Running;
+    }
+
+    // Define the phase of an in progress challenge driven by the join deadline and the start time.
+    pub fn get_scheduled_status(&self) -> ChallengeStatus {
+        let clock = Clock::get().unwrap();
+
+        if clock.unix_timestamp < self.join_deadline {
+            return ChallengeStatus::Open;
+        }
+
+        if clock.unix_timestamp < self.start_time.unwrap_or(self.join_deadline) {
+            return ChallengeStatus::Upcoming;
         }
+
+        return ChallengeStatus::Running;
+    }
+
+    // Define whether the stored status lags behind the scheduled phase.
+    pub fn is_status_transition_available(&self) -> bool {
+        return self.is_challenge_in_progress()
+            && self.status != self.get_scheduled_status();
     }
 
     // Define the state that the challenge is still open for participants.
     pub fn is_challenge_open_for_participants(&self) -> bool {
-        return self.status == ChallengeStatus::Created; // need to be updated once depositing occurs
+        return self.is_challenge_in_progress()
+            && self.get_scheduled_status() == ChallengeStatus::Open;
+    }
+
+    // Define the state that players can still leave the challenge before it starts.
+    pub fn is_challenge_open_for_leaving(&self) -> bool {
+        return self.is_challenge_in_progress()
+            && self.get_scheduled_status() != ChallengeStatus::Running;
     }
 
     // Define the state that the challenge is still open for participants.
     pub fn is_challenge_open_for_claim(&self) -> bool {
-        return self.status == ChallengeStatus::Finalized; // need to be updated once depositing occurs
+        return self.status == ChallengeStatus::Finalized
+            || (self.status == ChallengeStatus::PendingResult && !self.is_challenge_open_for_dispute());
+    }
+
+    // Define the state that the submitted winner list can still be disputed.
+    pub fn is_challenge_open_for_dispute(&self) -> bool {
+        let clock = Clock::get().unwrap();
+        return self.status == ChallengeStatus::PendingResult
+            && clock.unix_timestamp < self.dispute_deadline;
     }
 
     // Define the state that the challenge is still open for participants.
@@ -173,12 +610,270 @@ impl Challenge {
         return self.status == ChallengeStatus::Canceled; // need to be updated once depositing occurs
     }
 
+    // Define whether the join deadline has passed.
+    pub fn is_joining_closed(&self) -> bool {
+        let clock = Clock::get().unwrap();
+        return clock.unix_timestamp >= self.join_deadline;
+    }
+
+    // Define whether the challenge has reached its end time.
+    pub fn is_challenge_ended(&self) -> bool {
+        let clock = Clock::get().unwrap();
+        return clock.unix_timestamp >= self.end_time;
+    }
+
+    // Validate the configurable settings of the challenge
+    pub fn validate_settings(&self) -> Result<()> {
+        // The challenge must still be joinable and must not end before the join deadline
+        if self.is_joining_closed() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        if self.end_time < self.join_deadline {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // The challenge must start between the join deadline and the end time
+        if let Some(start_time) = self.start_time {
+            if start_time < self.join_deadline || start_time > self.end_time {
+                return Err(ChallengeError::InvalidValue.into());
+            }
+        }
+
+        if self.early_exit_penalty_bps as u64 > BPS_DENOMINATOR
+            || self.fee_bps as u64 > BPS_DENOMINATOR
+            || self.creator_fee_bps as u64 > BPS_DENOMINATOR {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // The caps must be reachable
+        if let Some(max_players) = self.max_players {
+            if max_players == 0 || max_players > MAX_PLAYERS_PER_CHALLENGE {
+                return Err(ChallengeError::InvalidValue.into());
+            }
+        }
+
+        if let Some(max_deposit) = self.max_deposit_per_player {
+            if max_deposit < self.min_deposit {
+                return Err(ChallengeError::InvalidValue.into());
+            }
+        }
+
+        if let Some(max_prize_pool) = self.max_prize_pool {
+            if max_prize_pool < self.min_deposit {
+                return Err(ChallengeError::InvalidValue.into());
+            }
+        }
+
+        // The milestones must be ascending and fall between the join deadline and the end time
+        if self.milestones.len() > MAX_MILESTONES_PER_CHALLENGE {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        if !self.milestones.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        if let (Some(first), Some(last)) = (self.milestones.first(), self.milestones.last()) {
+            if *first < self.join_deadline || *last >= self.end_time {
+                return Err(ChallengeError::InvalidValue.into());
+            }
+        }
+
+        // Only rolling over requires a designated challenge
+        if (self.no_winner_policy == NoWinnerPolicy::RollOver) != self.no_winner_rollover_target.is_some() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // Only paying out by rank requires a payout table, which must share the whole prize pool
+        if (self.payout_model == PayoutModel::Ranked) == self.payout_table.is_empty() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        if self.payout_table.len() > MAX_PAYOUT_RANKS {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        if !self.payout_table.is_empty()
+            && self.payout_table.iter().map(|bps| *bps as u64).sum::<u64>() != BPS_DENOMINATOR {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // Every rank must get a share, and a better rank never gets less
+        if self.payout_table.iter().any(|bps| *bps == 0)
+            || !self.payout_table.windows(2).all(|pair| pair[0] >= pair[1]) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        return Ok(());
+    }
+
+    // Validate the challenge against the registry, the series is only given when the challenge is created
+    pub fn validate_with_registry(&self, challenge_registry: &ChallengePlatformRegistry, challenge_series: Option<&AccountInfo>) -> Result<()> {
+        // Round ids of existing series are reserved so nobody can take the next round
+        if let Some(challenge_series) = challenge_series {
+            if ChallengeSeries::is_round_id_of(&self.id, challenge_series).unwrap() {
+                return Err(ChallengeError::InvalidValue.into());
+            }
+        }
+
+        // The deadlines and caps must be valid
+        self.validate_settings().unwrap();
+
+        // The creator fee must stay within the registry max
+        if !self.is_creator_fee_allowed(challenge_registry.max_creator_fee_bps) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // Check if the reward mint is allowed
+        if !challenge_registry.is_mint_account_enabled(self.reward_token_mint_account) {
+            return Err(ChallengeError::UnAllowedMintToken.into());
+        }
+
+        return Ok(());
+    }
+
+    // Define whether the winner list can still be amended within the correction window, a resolved dispute is final for the owner.
+    pub fn is_winner_list_amendable_for(&self, correction_window: i64, is_administrator: bool) -> bool {
+        let clock = Clock::get().unwrap();
+        return (self.status == ChallengeStatus::PendingResult || self.status == ChallengeStatus::Finalized)
+            && !self.is_any_reward_claimed()
+            && !self.is_fee_collected
+            && (!self.is_dispute_resolved || is_administrator)
+            && clock.unix_timestamp <= self.result_submitted_at + correction_window;
+    }
+
+    // Define whether the result is final without winners so the no-winner policy applies.
+    pub fn is_no_winner_policy_applicable(&self) -> bool {
+        return self.is_challenge_open_for_claim()
+            && self.get_total_winners().unwrap() == 0;
+    }
+
+    // Define whether the prize pool can be rolled over into the designated challenge.
+    pub fn is_challenge_open_for_no_winner_rollover_into(&self, target: &Challenge, target_key: Pubkey) -> bool {
+        return self.no_winner_policy == NoWinnerPolicy::RollOver
+            && self.no_winner_rollover_target == Some(target_key)
+            && self.reward_token_mint_account == target.reward_token_mint_account
+            && self.is_no_winner_policy_applicable()
+            && target.is_challenge_in_progress();
+    }
+
+    // Define whether the challenge was abandoned and can be expired by anyone.
+    pub fn is_challenge_expirable(&self, grace_period: i64) -> bool {
+        let clock = Clock::get().unwrap();
+        return self.is_challenge_in_progress()
+            && clock.unix_timestamp >= self.end_time + grace_period;
+    }
+
+    // Define whether the challenge settings can be updated for a pubkey.
+    pub fn is_challenge_updatable_for(&self, signer: &Pubkey) -> bool {
+        return self.is_challenge_in_progress()
+            && self.players.len() == 0
+            && self.prize_pool == 0
+            && self.is_challenge_owner(signer.clone());
+    }
+
     // Define whether the challenge can be canceled for a pubkey.
     pub fn is_challenge_cancelable_for(&self, signer: &Pubkey) -> bool {
-        return self.is_challenge_open_for_participants()
+        return self.is_challenge_in_progress()
             && self.is_challenge_owner(signer.clone());
     }
 
+    // Define whether administrators can cancel the challenge before any claim.
+    pub fn is_challenge_force_cancelable(&self) -> bool {
+        return (
+            self.is_challenge_in_progress()
+            || self.status == ChallengeStatus::PendingResult
+            || self.status == ChallengeStatus::Disputed
+            || self.status == ChallengeStatus::Finalized
+        ) && !self.is_any_reward_claimed()
+            && !self.is_creator_fee_claimed
+            && !self.is_any_referral_reward_claimed();
+    }
+
+    // Define whether the creator fee stays within the registry max.
+    pub fn is_creator_fee_allowed(&self, max_creator_fee_bps: u16) -> bool {
+        return self.creator_fee_bps <= max_creator_fee_bps;
+    }
+
+    // Define the state that players can submit proofs.
+    pub fn is_challenge_open_for_submission(&self) -> bool {
+        return self.is_challenge_in_progress();
+    }
+
+    // Define the state that submitted proofs can be reviewed before the result is final.
+    pub fn is_challenge_open_for_proof_review(&self) -> bool {
+        return self.is_challenge_in_progress()
+            || self.status == ChallengeStatus::PendingResult
+            || self.status == ChallengeStatus::Disputed;
+    }
+
+    // Define whether the winners are settled by milestone check-ins.
+    pub fn is_milestone_challenge(&self) -> bool {
+        return self.milestones.len() > 0;
+    }
+
+    // Define the state that players can check in the milestone at index.
+    pub fn is_check_in_open_for(&self, milestone_index: usize) -> bool {
+        let clock = Clock::get().unwrap();
+
+        if !self.is_challenge_in_progress() || milestone_index >= self.milestones.len() {
+            return false;
+        }
+
+        // a milestone can be checked in after the previous one has passed
+        let opened_at = match milestone_index {
+            0 => self.join_deadline,
+            _ => self.milestones[milestone_index - 1] + 1,
+        };
+
+        return clock.unix_timestamp >= opened_at
+            && clock.unix_timestamp <= self.milestones[milestone_index];
+    }
+
+    // Define whether the signer can record check-ins of a player.
+    pub fn is_check_in_recorder_for(&self, signer: Pubkey, player: Pubkey) -> bool {
+        return match self.judge {
+            Some(judge) => judge == signer,
+            None => player == signer,
+        };
+    }
+
+    // Define whether no more players can join, the account space is always a hard cap.
+    pub fn is_player_cap_reached(&self) -> bool {
+        let max_players = self.max_players
+            .unwrap_or(MAX_PLAYERS_PER_CHALLENGE)
+            .min(MAX_PLAYERS_PER_CHALLENGE);
+
+        return self.players.len() as u64 >= max_players;
+    }
+
+    // Define whether no more donors can donate, the account space is always a hard cap.
+    pub fn is_donor_cap_reached(&self) -> bool {
+        return self.donors.len() as u64 >= MAX_DONORS_PER_CHALLENGE;
+    }
+
+    // Define whether the player total deposit exceeds the cap after depositing an amount.
+    pub fn is_deposit_cap_exceeded(&self, pub_key: Pubkey, amount: u64) -> bool {
+        let current_deposit = self.players.iter()
+            .find(|player| player.public_key == pub_key)
+            .map(|player| player.total_deposit)
+            .unwrap_or(0);
+
+        return match self.max_deposit_per_player {
+            Some(max_deposit) => current_deposit + amount > max_deposit,
+            None => false,
+        };
+    }
+
+    // Define whether the prize pool exceeds the cap after receiving an amount.
+    pub fn is_prize_pool_cap_exceeded(&self, amount: u64) -> bool {
+        return match self.max_prize_pool {
+            Some(max_prize_pool) => self.prize_pool + amount > max_prize_pool,
+            None => false,
+        };
+    }
+
     // find player for mutation
     pub fn get_or_create_player(&mut self, pub_key: Pubkey) -> Result<&mut PlayerInfo> {
         if !self.is_player(pub_key) {
@@ -188,6 +883,81 @@ impl Challenge {
         return self.find_player_for_mutation(pub_key);
     }
 
+    // find donor for mutation, the donor is recorded on the first donation
+    pub fn get_or_create_donor(&mut self, pub_key: Pubkey) -> Result<&mut DonorInfo> {
+        if !self.is_donor(pub_key) {
+            self.donors.push(
+                DonorInfo {
+                    public_key: pub_key,
+                    total_donation: 0,
+                    is_donation_refunded: false,
+                }
+            );
+        }
+
+        return self.find_donor_for_mutation(pub_key);
+    }
+
+    // Define whether the signer is a donor
+    pub fn is_donor(&self, signer: Pubkey) -> bool {
+        return self.donors.iter()
+            .any(|donor| donor.public_key == signer);
+    }
+
+    // find donor for mutation
+    pub fn find_donor_for_mutation(&mut self, donor: Pubkey) -> Result<&mut DonorInfo> {
+        let donor = self.donors
+            .iter_mut()
+            .find(|dn| dn.public_key == donor)
+            .unwrap();
+
+        return Ok(donor);
+    }
+
+    // Define whether no more referrers can be recorded, the account space is always a hard cap.
+    pub fn is_referrer_cap_reached(&self) -> bool {
+        return self.referrers.len() as u64 >= MAX_REFERRERS_PER_CHALLENGE;
+    }
+
+    // Define whether the signer is a referrer
+    pub fn is_referrer(&self, signer: Pubkey) -> bool {
+        return self.referrers.iter()
+            .any(|referrer| referrer.public_key == signer);
+    }
+
+    // get the position of a referrer, the referrer is recorded if needed
+    pub fn get_or_create_referrer_index(&mut self, pub_key: Pubkey) -> Result<u8> {
+        if !self.is_referrer(pub_key) {
+            if self.is_referrer_cap_reached() {
+                return Err(ChallengeError::MaxReferrersReached.into());
+            }
+
+            self.referrers.push(
+                ReferrerInfo {
+                    public_key: pub_key,
+                    referral_reward: 0,
+                    is_referral_reward_claimed: false,
+                }
+            );
+        }
+
+        let index = self.referrers.iter()
+            .position(|referrer| referrer.public_key == pub_key)
+            .unwrap();
+
+        return Ok(index as u8);
+    }
+
+    // find referrer for mutation
+    pub fn find_referrer_for_mutation(&mut self, referrer: Pubkey) -> Result<&mut ReferrerInfo> {
+        let referrer = self.referrers
+            .iter_mut()
+            .find(|rf| rf.public_key == referrer)
+            .unwrap();
+
+        return Ok(referrer);
+    }
+
     // add player
     pub fn add_player(&mut self, pub_key: Pubkey, total_deposit: u64) -> Result<()> {
         if self.is_player(pub_key) {
@@ -201,12 +971,27 @@ impl Challenge {
                 is_winner: false,
                 total_deposit,
                 is_player_withdrawn: false,
+                checked_in_milestones: 0,
+                is_eliminated: false,
+                rank: 0,
+                referrer_index: None,
             }
         );
 
         return Ok(());
     }
 
+    // remove player
+    pub fn remove_player(&mut self, pub_key: Pubkey) -> Result<()> {
+        if !self.is_player(pub_key) {
+            return Err(ChallengeError::OnlyParticipant.into());
+        }
+
+        self.players.retain(|player| player.public_key != pub_key);
+
+        return Ok(());
+    }
+
     // mutate player
     pub fn mutate_player(&mut self, player: PlayerInfo) -> Result<()> {
         let player = &player;
@@ -226,7 +1011,116 @@ impl Challenge {
     }
 
 
-    // Check whether the challenge owner is the signer.
+    // replace the winner list, every winner must be a player
+    pub fn set_winner_list(&mut self, winner_list: Vec<Pubkey>) -> Result<()> {
+        if !winner_list.iter().all(|winner| self.is_player(winner.clone())) {
+            return Err(ChallengeError::OnlyParticipant.into());
+        }
+
+        // a winner can only hold one rank
+        if winner_list.iter().enumerate().any(|(index, winner)| winner_list[..index].contains(winner)) {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // every ranked winner must have a share in the payout table
+        if self.payout_model == PayoutModel::Ranked && winner_list.len() > self.payout_table.len() {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        // the winner list is an ordered ranking
+        self.players.iter_mut().for_each(|player| {
+            let position = winner_list.iter().position(|winner| *winner == player.public_key);
+            player.is_winner = position.is_some();
+            player.rank = position.map(|index| index as u8 + 1).unwrap_or(0);
+        });
+
+        return Ok(());
+    }
+
+    // record the check-in of a player for the milestone at index
+    pub fn record_check_in(&mut self, pub_key: Pubkey, milestone_index: usize) -> Result<()> {
+        if !self.is_player(pub_key) {
+            return Err(ChallengeError::OnlyParticipant.into());
+        }
+
+        let player = self.find_player_for_mutation(pub_key).unwrap();
+
+        if player.is_eliminated {
+            return Err(ChallengeError::PlayerEliminated.into());
+        }
+
+        player.checked_in_milestones |= 1 << milestone_index;
+
+        return Ok(());
+    }
+
+    // mark players who missed a passed milestone as eliminated, returns the newly eliminated players
+    pub fn eliminate_missed_players(&mut self) -> Result<Vec<Pubkey>> {
+        let clock = Clock::get().unwrap();
+        let passed_milestones = self.milestones.iter()
+            .filter(|&&milestone| milestone < clock.unix_timestamp)
+            .count();
+
+        let required_mask = ((1u64 << passed_milestones) - 1) as u32;
+        let mut eliminated_players = vec![];
+
+        self.players.iter_mut()
+            .filter(|player| !player.is_eliminated)
+            .filter(|player| player.checked_in_milestones & required_mask != required_mask)
+            .for_each(|player| {
+                player.is_eliminated = true;
+                eliminated_players.push(player.public_key.clone());
+            });
+
+        return Ok(eliminated_players);
+    }
+
+    // Define the players that have not been eliminated
+    pub fn get_survivors(&self) -> Result<Vec<Pubkey>> {
+        return Ok(self.players.clone()
+            .into_iter()
+            .filter(|player| !player.is_eliminated)
+            .map(|player| player.public_key)
+            .collect()
+        );
+    }
+
+    // move the challenge to another status, only the allowed transitions are accepted
+    pub fn transition_to(&mut self, to: ChallengeStatus, actor: Pubkey, challenge_key: Pubkey) -> Result<()> {
+        if !self.status.can_transition_to(to) {
+            return Err(ChallengeError::StatusTransitionIsNotAllowed.into());
+        }
+
+        let from = self.status;
+        self.status = to;
+
+        // emit event
+        challenge_emit!(
+            ChallengeStatusChanged {
+                actor,
+                challenge_key,
+                id: self.id.clone(),
+                from,
+                to,
+            }
+        );
+
+        return Ok(());
+    }
+
+    // submit the winner list, it can be disputed until the window lapses
+    pub fn submit_result(&mut self, winner_list: Vec<Pubkey>, dispute_window: i64, actor: Pubkey, challenge_key: Pubkey) -> Result<()> {
+        self.transition_to(ChallengeStatus::PendingResult, actor, challenge_key).unwrap();
+        self.set_winner_list(winner_list).unwrap();
+
+        let clock = Clock::get().unwrap();
+        self.dispute_deadline = clock.unix_timestamp + dispute_window;
+        self.result_submitted_at = clock.unix_timestamp;
+
+        return Ok(());
+    }
+
+    // Check whether the challenge owner is the signer.
     pub fn is_challenge_owner(&self, signer: Pubkey) -> bool {
         return self.owner == signer.key().clone();
     }
@@ -258,6 +1152,47 @@ impl Challenge {
             .count() == 1;
     }
 
+    // Define whether any winner claimed reward
+    pub fn is_any_reward_claimed(&self) -> bool {
+        return self.players.iter()
+            .any(|player| player.is_winner_claimed_reward == true);
+    }
+
+    // Define whether the winner already claimed the reward
+    pub fn is_reward_claimed_by(&self, signer: Pubkey) -> bool {
+        return self.players.iter()
+            .any(|player| player.public_key == signer && player.is_winner_claimed_reward == true);
+    }
+
+    // Define whether any referrer claimed the referral reward
+    pub fn is_any_referral_reward_claimed(&self) -> bool {
+        return self.referrers.iter()
+            .any(|referrer| referrer.is_referral_reward_claimed == true);
+    }
+
+    // Define the winner list ordered by rank
+    pub fn get_winner_list(&self) -> Result<Vec<Pubkey>> {
+        let mut winners: Vec<PlayerInfo> = self.players.clone()
+            .into_iter()
+            .filter(|player| player.is_winner == true)
+            .collect();
+        winners.sort_by_key(|player| player.rank);
+
+        return Ok(winners.into_iter()
+            .map(|player| player.public_key)
+            .collect()
+        );
+    }
+
+    // Define the method to get total winners
+    pub fn get_total_winners(&self) -> Result<u64> {
+        return Ok(self.players.clone()
+            .into_iter()
+            .filter(|player| player.is_winner == true)
+            .count() as u64
+        );
+    }
+
     // Define the method to get total winners that claimed reward
     pub fn get_total_unclaimed_winners(&self) -> Result<u64> {
         return Ok(self.players.clone()
@@ -287,12 +1222,259 @@ impl Challenge {
             return Ok(0);
         }
 
+        let share = self.get_share_for(&player).unwrap();
+        let remainder = self.get_payout_remainder_for(&player).unwrap();
+
+        return Ok(share + remainder);
+    }
+
+    // Define the share of the prize pool for a winner following the payout model
+    pub fn get_share_for(&self, player: &PlayerInfo) -> Result<u64> {
         let total_winners = self.players.clone()
             .into_iter()
             .filter(|player| player.is_winner == true)
             .count();
 
-        return Ok(self.prize_pool / total_winners as u64);
+        return match self.payout_model {
+            PayoutModel::EqualSplit => Ok(self.prize_pool / total_winners as u64),
+            PayoutModel::Ranked => {
+                // the unused ranks are shared between the ranked winners in proportion
+                let used_bps: u64 = self.payout_table.iter()
+                    .take(total_winners)
+                    .map(|bps| *bps as u64)
+                    .sum();
+                let rank_bps = self.payout_table[player.rank as usize - 1] as u64;
+
+                Ok((self.prize_pool as u128 * rank_bps as u128 / used_bps as u128) as u64)
+            },
+            PayoutModel::StakeProportional => {
+                let total_winner_deposit = self.get_total_winner_deposit().unwrap();
+
+                // winners without any deposit share the prize pool equally
+                if total_winner_deposit == 0 {
+                    return Ok(self.prize_pool / total_winners as u64);
+                }
+
+                Ok((self.prize_pool as u128 * player.total_deposit as u128 / total_winner_deposit as u128) as u64)
+            },
+            PayoutModel::StakeBack => {
+                let forfeited_pool = self.get_forfeited_pool().unwrap();
+
+                Ok(player.total_deposit + forfeited_pool / total_winners as u64)
+            },
+        };
+    }
+
+    // Define the payout remainder assigned to a winner
+    pub fn get_payout_remainder_for(&self, player: &PlayerInfo) -> Result<u64> {
+        let is_receiver = match self.payout_remainder_policy {
+            PayoutRemainderPolicy::LastClaimer => self.get_total_unclaimed_winners().unwrap() == 1,
+            PayoutRemainderPolicy::TopRankedWinner => player.rank == 1,
+            PayoutRemainderPolicy::Treasury => false,
+        };
+
+        if !is_receiver {
+            return Ok(0);
+        }
+
+        return Ok(self.payout_remainder);
+    }
+
+    // track the amount left over by the payout shares once the result is final, returns the amount going to the treasury
+    pub fn settle_payout_remainder(&mut self) -> Result<u64> {
+        if self.is_payout_remainder_settled {
+            return Ok(0);
+        }
+
+        let total_shares: u64 = self.players.iter()
+            .filter(|player| player.is_winner == true)
+            .map(|player| self.get_share_for(player).unwrap())
+            .sum();
+        self.payout_remainder = self.prize_pool.saturating_sub(total_shares);
+        self.is_payout_remainder_settled = true;
+
+        if self.payout_remainder_policy == PayoutRemainderPolicy::Treasury {
+            return Ok(self.payout_remainder);
+        }
+
+        return Ok(0);
+    }
+
+    // Define the deposits of non-winners and the donations left after the winners get their deposits back
+    pub fn get_forfeited_pool(&self) -> Result<u64> {
+        return Ok(self.prize_pool.saturating_sub(self.get_total_winner_deposit().unwrap()));
+    }
+
+    // Define the total deposit of the winners
+    pub fn get_total_winner_deposit(&self) -> Result<u64> {
+        return Ok(self.players.iter()
+            .filter(|player| player.is_winner == true)
+            .map(|player| player.total_deposit)
+            .sum()
+        );
+    }
+
+    // take the challenge and protocol fees from the prize pool once the result is final, returns the amount kept in the treasury
+    pub fn collect_fee(&mut self, protocol_fee_bps: u16, referral_bps: u16, referral_fee_source: ReferralFeeSource) -> Result<u64> {
+        if self.is_fee_collected {
+            return Ok(0);
+        }
+
+        // winners always get their deposits back when paying out stakes back
+        let fee_base = match self.payout_model {
+            PayoutModel::StakeBack => self.get_forfeited_pool().unwrap(),
+            _ => self.prize_pool,
+        };
+
+        let total_fee_bps = (self.fee_bps as u64 + protocol_fee_bps as u64).min(BPS_DENOMINATOR);
+        let fee_amount = (fee_base as u128 * total_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+        // the creator fee is kept for the challenge owner and never exceeds what is left
+        let creator_fee_bps = (self.creator_fee_bps as u64).min(BPS_DENOMINATOR - total_fee_bps);
+        let creator_fee_amount = (fee_base as u128 * creator_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+        self.prize_pool -= fee_amount + creator_fee_amount;
+        self.collected_fee = fee_amount;
+        self.creator_fee = creator_fee_amount;
+        self.is_fee_collected = true;
+
+        // the referrers are paid out of the configured fee
+        match referral_fee_source {
+            ReferralFeeSource::ProtocolFee => {
+                self.collected_fee -= self.distribute_referral_rewards(fee_amount, referral_bps).unwrap();
+            },
+            ReferralFeeSource::CreatorFee => {
+                self.creator_fee -= self.distribute_referral_rewards(creator_fee_amount, referral_bps).unwrap();
+            },
+        }
+
+        return Ok(self.collected_fee);
+    }
+
+    // split the referral share of a fee by the deposits each referrer brought in, returns the distributed amount
+    fn distribute_referral_rewards(&mut self, fee_amount: u64, referral_bps: u16) -> Result<u64> {
+        let total_deposit = self.get_total_deposit().unwrap();
+        if total_deposit == 0 {
+            return Ok(0);
+        }
+
+        let referral_bps = (referral_bps as u64).min(BPS_DENOMINATOR);
+        let mut distributed_amount = 0;
+
+        for index in 0..self.referrers.len() {
+            let referred_deposit: u64 = self.players.iter()
+                .filter(|player| player.referrer_index == Some(index as u8))
+                .map(|player| player.total_deposit)
+                .sum();
+
+            let reward = (fee_amount as u128 * referral_bps as u128 * referred_deposit as u128
+                / (BPS_DENOMINATOR as u128 * total_deposit as u128)) as u64;
+
+            self.referrers[index].referral_reward = reward;
+            distributed_amount += reward;
+        }
+
+        return Ok(distributed_amount);
+    }
+
+    // Define the referral reward the referrer can still claim
+    pub fn get_claimable_referral_reward_for(&self, signer: Pubkey) -> Result<u64> {
+        if !self.is_fee_collected {
+            return Ok(0);
+        }
+
+        let referral_reward = self.referrers.iter()
+            .find(|referrer| referrer.public_key == signer && !referrer.is_referral_reward_claimed)
+            .map(|referrer| referrer.referral_reward)
+            .unwrap_or(0);
+
+        return Ok(referral_reward);
+    }
+
+    // Define the creator fee the challenge owner can still claim
+    pub fn get_claimable_creator_fee(&self) -> Result<u64> {
+        if !self.is_fee_collected || self.is_creator_fee_claimed {
+            return Ok(0);
+        }
+
+        return Ok(self.creator_fee);
+    }
+
+    // Define the function to get the early exit penalty for signer
+    pub fn get_early_exit_penalty_for(&self, signer: Pubkey) -> Result<u64> {
+        let player = self.players.clone()
+            .into_iter()
+            .find(|player| player.public_key == signer)
+            .unwrap();
+
+        return Ok((player.total_deposit as u128 * self.early_exit_penalty_bps as u128 / BPS_DENOMINATOR as u128) as u64);
+    }
+
+    // Define the state that the challenge is settled without outstanding liabilities.
+    pub fn is_challenge_settled(&self) -> bool {
+        return match self.status {
+            ChallengeStatus::Claimed => {
+                self.get_total_unclaimed_winners().unwrap() == 0
+                    && (self.is_creator_fee_claimed || self.creator_fee == 0)
+                    && self.referrers.iter()
+                        .all(|referrer| referrer.is_referral_reward_claimed || referrer.referral_reward == 0)
+            },
+            ChallengeStatus::Canceled | ChallengeStatus::Withdrawn => {
+                self.get_total_unwithdrawn_player().unwrap() == 0 && self.donate_pool == 0
+            },
+            _ => false,
+        };
+    }
+
+    // Define the state that the challenge is settled and its dispute and submissions are closed,
+    // so a challenge recreated with the same id can not collide with them.
+    pub fn is_challenge_closable(&self) -> bool {
+        return self.is_challenge_settled()
+            && self.total_submissions == 0
+            && !self.is_dispute_opened;
+    }
+
+    // Define the early exit penalties and rolled over funds left in the prize pool of a canceled challenge, nobody can claim them
+    pub fn get_unclaimable_pool(&self) -> Result<u64> {
+        return match self.status {
+            ChallengeStatus::Canceled | ChallengeStatus::Withdrawn => {
+                Ok(self.prize_pool.saturating_sub(self.get_total_deposit().unwrap() + self.donate_pool))
+            },
+            _ => Ok(0),
+        };
+    }
+
+    // Define the total deposit of the players
+    pub fn get_total_deposit(&self) -> Result<u64> {
+        return Ok(self.players.iter()
+            .map(|player| player.total_deposit)
+            .sum()
+        );
+    }
+
+    // Define the state that donors can get their donations back.
+    pub fn is_challenge_open_for_donation_refund(&self) -> bool {
+        return self.status == ChallengeStatus::Canceled
+            || self.status == ChallengeStatus::Withdrawn;
+    }
+
+    // Define the state that administrators can sweep the donations, nobody is owed them before the challenge ends
+    pub fn is_challenge_open_for_donate_pool_sweep(&self) -> bool {
+        return self.is_challenge_in_progress();
+    }
+
+    // Define the function to get the donation refund for signer
+    pub fn get_donation_refund_for(&self, signer: Pubkey) -> Result<u64> {
+        let donor = self.donors.clone()
+            .into_iter()
+            .find(|donor| donor.public_key == signer)
+            .unwrap();
+
+        if donor.is_donation_refunded {
+            return Ok(0);
+        }
+
+        return Ok(donor.total_donation);
     }
 
     // Define the function to get prize for signer
@@ -308,4 +1490,351 @@ impl Challenge {
 
         return Ok(player.total_deposit);
     }
-}
\ No newline at end of file
+}
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum DisputeStatus {
+    // Declare that the dispute is waiting for administrators
+    #[default]
+    Opened,
+
+    // Declare that administrators kept the submitted winner list
+    Upheld,
+
+    // Declare that administrators replaced the submitted winner list
+    Replaced,
+}
+
+// Here we define the dispute raised against a submitted winner list. ChallengeDispute will be the PDA.
+#[account]
+#[derive(Default)]
+pub struct ChallengeDispute {
+    // Bump to help define the PDA of dispute.
+    pub bump: u8,
+
+    // Define the disputed challenge
+    pub challenge: Pubkey,
+
+    // Define the participant who opened the dispute
+    pub disputer: Pubkey,
+
+    // Define the uri describing the dispute reason
+    pub reason_uri: String,
+
+    // Define the timestamp the dispute was opened
+    pub opened_at: i64,
+
+    // Define the dispute status
+    pub status: DisputeStatus,
+
+    // Define the administrator who resolved the dispute
+    pub resolver: Pubkey,
+
+    // Define the timestamp the dispute was resolved
+    pub resolved_at: i64,
+}
+
+// Implement some domain logic
+impl ChallengeDispute {
+    // Define the account space
+    pub const SPACE: usize = 8 + 1 + 32 + 32 + (4 + MAX_URI_LENGTH) + 8 + 1 + 32 + 8;
+}
+
+// Here we define the descriptive data of a challenge. ChallengeMetadata will be the PDA.
+#[account]
+#[derive(Default)]
+pub struct ChallengeMetadata {
+    // Bump to help define the PDA of metadata.
+    pub bump: u8,
+
+    // Define the described challenge
+    pub challenge: Pubkey,
+
+    // Define the title
+    pub title: String,
+
+    // Define the uri of the description
+    pub description_uri: String,
+
+    // Define the category
+    pub category: String,
+
+    // Define the hash of the rules content
+    pub rules_hash: [u8; 32],
+
+    // Define the uri of the cover image
+    pub cover_image_uri: String,
+}
+
+// Implement some domain logic
+impl ChallengeMetadata {
+    // Define the account space
+    pub const SPACE: usize = 8 + 1 + 32
+        + (4 + MAX_TITLE_LENGTH)
+        + (4 + MAX_URI_LENGTH)
+        + (4 + MAX_CATEGORY_LENGTH)
+        + 32
+        + (4 + MAX_URI_LENGTH);
+
+    // assign metadata and validate the length of text fields
+    pub fn set_metadata(&mut self, params: ChallengeMetadataParams) -> Result<()> {
+        if params.title.len() > MAX_TITLE_LENGTH
+            || params.description_uri.len() > MAX_URI_LENGTH
+            || params.category.len() > MAX_CATEGORY_LENGTH
+            || params.cover_image_uri.len() > MAX_URI_LENGTH {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        self.title = params.title;
+        self.description_uri = params.description_uri;
+        self.category = params.category;
+        self.rules_hash = params.rules_hash;
+        self.cover_image_uri = params.cover_image_uri;
+
+        return Ok(());
+    }
+}
+
+// Here we define the template of a recurring challenge. ChallengeSeries will be the PDA.
+#[account]
+#[derive(Default)]
+pub struct ChallengeSeries {
+    // Id of the series
+    pub id: String,
+
+    // Bump to help define the PDA of series.
+    pub bump: u8,
+
+    // Define the owner of the series
+    pub owner: Pubkey,
+
+    // Define the reward token of every round
+    pub reward_token_mint_account: Pubkey,
+
+    // Define minimum deposit of every round
+    pub min_deposit: u64,
+
+    // Define how long players can join after a round is spawned
+    pub join_duration: i64,
+
+    // Define how long a round lasts after it is spawned
+    pub duration: i64,
+
+    // Define the latest spawned round
+    pub round: u64,
+
+    // Define whether zero-winner prize pools roll over into the next round
+    pub rollover_enabled: bool,
+}
+
+// Implement some domain logic
+impl ChallengeSeries {
+    // Define the account space
+    pub const SPACE: usize = 8 + (4 + MAX_SERIES_ID_LENGTH) + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    // Define the derived challenge id of a round
+    pub fn get_round_id(&self, round: u64) -> String {
+        return format!("{}-{}", self.id, round);
+    }
+
+    // Define the derived challenge key of a round
+    pub fn get_round_key(&self, round: u64) -> Pubkey {
+        let (round_key, _) = Pubkey::find_program_address(
+            &[CHALLENGE_SEED, self.get_round_id(round).as_bytes().as_ref()],
+            &crate::ID,
+        );
+
+        return round_key;
+    }
+
+    // Define the id of the series an id would be a round of
+    pub fn get_series_id_of(id: &str) -> Option<String> {
+        return match id.rsplit_once('-') {
+            Some((series_id, round)) => {
+                if series_id.is_empty()
+                    || series_id.len() > MAX_SERIES_ID_LENGTH
+                    || round.is_empty()
+                    || !round.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+
+                Some(series_id.to_string())
+            },
+            None => None,
+        };
+    }
+
+    // Define whether an id is reserved as a round id of an existing series
+    pub fn is_round_id_of(id: &str, challenge_series: &AccountInfo) -> Result<bool> {
+        let series_id = match ChallengeSeries::get_series_id_of(id) {
+            Some(series_id) => series_id,
+            None => return Ok(false),
+        };
+
+        // the series account must be the one the id would be a round of
+        let (series_key, _) = Pubkey::find_program_address(
+            &[SERIES_SEED, series_id.as_bytes().as_ref()],
+            &crate::ID,
+        );
+        if challenge_series.key() != series_key {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        return Ok(challenge_series.owner == &crate::ID && !challenge_series.data_is_empty());
+    }
+
+    // Check whether the series owner is the signer.
+    pub fn is_series_owner(&self, signer: Pubkey) -> bool {
+        return self.owner == signer.key().clone();
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub enum SubmissionStatus {
+    // Declare that the submission is waiting for review
+    #[default]
+    Pending,
+
+    // Declare that the submission was accepted
+    Accepted,
+
+    // Declare that the submission was rejected
+    Rejected,
+}
+
+// Here we define the proof submitted by a player. ChallengeSubmission will be the PDA.
+#[account]
+#[derive(Default)]
+pub struct ChallengeSubmission {
+    // Bump to help define the PDA of submission.
+    pub bump: u8,
+
+    // Define the challenge the proof is submitted to
+    pub challenge: Pubkey,
+
+    // Define the player who submitted the proof
+    pub player: Pubkey,
+
+    // Define the uri of the evidence
+    pub evidence_uri: String,
+
+    // Define the hash of the evidence content
+    pub content_hash: [u8; 32],
+
+    // Define the timestamp the proof was submitted
+    pub submitted_at: i64,
+
+    // Define the submission status
+    pub status: SubmissionStatus,
+
+    // Define the owner or administrator who reviewed the submission
+    pub reviewer: Pubkey,
+
+    // Define the timestamp the submission was reviewed
+    pub reviewed_at: i64,
+}
+
+// Implement some domain logic
+impl ChallengeSubmission {
+    // Define the account space
+    pub const SPACE: usize = 8 + 1 + 32 + 32 + (4 + MAX_URI_LENGTH) + 32 + 8 + 1 + 32 + 8;
+}
+
+// Here we define the funds owned by the platform for a mint. ChallengeTreasury will be the PDA.
+#[account]
+#[derive(Default)]
+pub struct ChallengeTreasury {
+    // Bump to help define the PDA of treasury.
+    pub bump: u8,
+
+    // Define the mint of the treasury funds
+    pub mint_account: Pubkey,
+
+    // Define the treasury funds held in the token vault
+    pub balance: u64,
+}
+
+// Implement some domain logic
+impl ChallengeTreasury {
+    // Define the account space
+    pub const SPACE: usize = 8 + 1 + 32 + 8;
+}
+
+// Here we define the curated settings a challenge can be created from. ChallengeTemplate will be the PDA.
+#[account]
+#[derive(Default)]
+pub struct ChallengeTemplate {
+    // Id of the template
+    pub id: String,
+
+    // Bump to help define the PDA of template.
+    pub bump: u8,
+
+    // Define whether challenges can be created from the template
+    pub is_enabled: bool,
+
+    // Define the reward token of the challenges
+    pub reward_token_mint_account: Pubkey,
+
+    // Define the range a player can deposit
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+
+    // Define the range of the challenge duration
+    pub min_duration: i64,
+    pub max_duration: i64,
+
+    // Define how the prize pool is split between winners
+    pub payout_model: PayoutModel,
+
+    // Define the fee in basis points taken from the prize pool
+    pub fee_bps: u16,
+}
+
+// Implement some domain logic
+impl ChallengeTemplate {
+    // Define the account space
+    pub const SPACE: usize = 8 + (4 + MAX_TEMPLATE_ID_LENGTH) + 1 + 1 + 32 + 8 + 8 + 8 + 8 + 1 + 2;
+
+    // assign the template settings and validate the ranges
+    pub fn set_template(&mut self, params: ChallengeTemplateParams) -> Result<()> {
+        if params.id.len() > MAX_TEMPLATE_ID_LENGTH
+            || params.min_deposit > params.max_deposit
+            || params.min_duration > params.max_duration
+            || params.fee_bps as u64 > BPS_DENOMINATOR {
+            return Err(ChallengeError::InvalidValue.into());
+        }
+
+        self.id = params.id;
+        self.is_enabled = params.is_enabled;
+        self.reward_token_mint_account = params.reward_token_mint_account;
+        self.min_deposit = params.min_deposit;
+        self.max_deposit = params.max_deposit;
+        self.min_duration = params.min_duration;
+        self.max_duration = params.max_duration;
+        self.payout_model = params.payout_model;
+        self.fee_bps = params.fee_bps;
+
+        return Ok(());
+    }
+
+    // validate the creator settings of a challenge against the template
+    pub fn validate_challenge(&self, challenge: &Challenge) -> Result<()> {
+        let clock = Clock::get().unwrap();
+        let duration = challenge.end_time - clock.unix_timestamp;
+
+        if challenge.reward_token_mint_account != self.reward_token_mint_account
+            || challenge.min_deposit < self.min_deposit
+            || challenge.min_deposit > self.max_deposit
+            || duration < self.min_duration
+            || duration > self.max_duration {
+            return Err(ChallengeError::TemplateConstraintViolated.into());
+        }
+
+        // players can never deposit more than the template allows
+        if challenge.max_deposit_per_player.unwrap_or(u64::MAX) > self.max_deposit {
+            return Err(ChallengeError::TemplateConstraintViolated.into());
+        }
+
+        return Ok(());
+    }
+}
@@ -1,6 +1,11 @@
 import * as anchor from '@project-serum/anchor';
 import { BN, Program } from '@project-serum/anchor';
-import { Keypair, Transaction, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
 import { expect } from 'chai';
 import {
   Account,
@@ -56,6 +61,8 @@ describe('[cancel_challenge_and_withdraw_reward]', async () => {
     id: Keypair.generate().publicKey.toBase58().slice(0, 10),
     minDeposit: new BN(LAMPORTS_PER_SOL * 10),
     rewardTokenMintAccount: null,
+    joinDeadline: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    endTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
   };
 
   /**
@@ -241,6 +248,8 @@ describe('[cancel_challenge_and_withdraw_reward]', async () => {
       challengeId: challengeInfo.id,
       rewardMintAddress: challengeInfo.rewardTokenMintAccount,
       minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
       signer: challengeOwnerKeypair.publicKey,
     });
 
@@ -362,14 +371,31 @@ describe('[cancel_challenge_and_withdraw_reward]', async () => {
     ]);
 
     /**
-     * @dev Player 2 claim reward
+     * @dev Administrator cannot sweep the donations of a canceled challenge
      */
     const ins4 = await workspace.instructionBuilder.adminWithdrawDonatePool({
       challengeId: challengeInfo.id,
       signer: administrator.publicKey,
     });
-    await workspace.provider.sendAndConfirm(new Transaction().add(...ins4), [
-      administrator,
+    try {
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...ins4),
+        [administrator],
+      );
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    /**
+     * @dev Donor gets the donation back
+     */
+    const ins5 = await workspace.instructionBuilder.refundDonation({
+      challengeId: challengeInfo.id,
+      signer: donor.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins5), [
+      donor,
     ]);
 
     /**
@@ -438,7 +464,202 @@ describe('[cancel_challenge_and_withdraw_reward]', async () => {
     expect(Number(player1TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
     expect(Number(player2TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
     expect(Number(player3TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
-    expect(Number(donorTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 96);
-    expect(Number(administratorTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 4);
+    expect(Number(donorTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
+    expect(Number(administratorTokenAccount.amount)).eq(0);
+  });
+
+  it('[withdraw_reward] should: vault transfers are signed with the registry bump', async () => {
+    /**
+     * @dev Pick a challenge id whose bump differs from the registry bump
+     */
+    const {
+      address: [, registryBump],
+    } = await workspace.programFinder.getChallengeRegistryAccount();
+    let challengeId: string;
+    do {
+      challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+    } while (
+      (await workspace.programFinder.getChallengeAccount(challengeId))
+        .address[1] === registryBump
+    );
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId,
+      amount: challengeInfo.minDeposit,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player1],
+    );
+
+    const cancelIns = await workspace.instructionBuilder.cancelChallenge({
+      challengeId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...cancelIns),
+      [challengeOwnerKeypair],
+    );
+
+    /**
+     * @dev The vault authority is the registry so the withdrawal goes through
+     */
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId,
+        signer: player1.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [player1],
+    );
+
+    player1TokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player1,
+      challengeInfo.rewardTokenMintAccount,
+      player1.publicKey,
+    );
+    expect(Number(player1TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
+  });
+
+  it('[withdraw_reward] should: fail to withdraw through the vault of another mint', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId,
+      amount: challengeInfo.minDeposit,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player1],
+    );
+
+    const cancelIns = await workspace.instructionBuilder.cancelChallenge({
+      challengeId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...cancelIns),
+      [challengeOwnerKeypair],
+    );
+
+    /**
+     * @dev Whitelist another mint with its own vault and treasury
+     */
+    const otherMint = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: otherMint.toBase58(),
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+    const otherTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player1,
+      otherMint,
+      player1.publicKey,
+    );
+
+    /**
+     * @dev The withdrawal must go through the vault of the challenge mint
+     */
+    const {
+      address: [challengePubkey],
+    } = await workspace.programFinder.getChallengeAccount(challengeId);
+    const {
+      address: [otherTokenVault, otherTokenVaultBump],
+    } = await workspace.programFinder.getTokenVaultAccount(
+      otherMint.toBase58(),
+    );
+    const {
+      address: [otherTreasury],
+    } = await workspace.programFinder.getTreasuryAccount(otherMint.toBase58());
+    const {
+      address: [challengeRegistryPubkey],
+    } = await workspace.programFinder.getChallengeRegistryAccount();
+    const ins = await program.methods
+      .transferAssetsFromVault({
+        // @ts-ignore
+        actionType: { withdrawing: {} },
+        challengeId,
+        challengeTokenVaultBump: otherTokenVaultBump,
+      })
+      .accounts({
+        signer: player1.publicKey,
+        challenge: challengePubkey,
+        challengeTokenVault: otherTokenVault,
+        signerTokenAccount: otherTokenAccount.address,
+        challengeRegistry: challengeRegistryPubkey,
+        challengeTreasury: otherTreasury,
+        mintAccount: otherMint,
+      })
+      .instruction();
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(ins), [
+        player1,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    /**
+     * @dev The deposit is still withdrawable from the challenge vault
+     */
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId,
+        signer: player1.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [player1],
+    );
+
+    player1TokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player1,
+      challengeInfo.rewardTokenMintAccount,
+      player1.publicKey,
+    );
+    expect(Number(player1TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
   });
 });
@@ -0,0 +1,284 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[challenge_caps]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player1 = Keypair.generate();
+  const player2 = Keypair.generate();
+  const donor = Keypair.generate();
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    maxPlayersId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    maxDepositId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    maxPrizePoolId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+  };
+
+  /**
+   * @dev Send a transaction that is expected to fail
+   */
+  const expectFailure = async (
+    ins: anchor.web3.TransactionInstruction[],
+    signer: Keypair,
+  ) => {
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        signer,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player1,
+      player2,
+      donor,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players and donor
+     */
+    for (const keypair of [player1, player2, donor]) {
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        keypair,
+        challengeInfo.rewardTokenMintAccount,
+        keypair.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        tokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev Each challenge sets one of the caps
+     */
+    for (const [challengeId, caps] of [
+      [challengeInfo.maxPlayersId, { maxPlayers: new BN(1) }],
+      [
+        challengeInfo.maxDepositId,
+        { maxDepositPerPlayer: new BN(LAMPORTS_PER_SOL * 15) },
+      ],
+      [
+        challengeInfo.maxPrizePoolId,
+        { maxPrizePool: new BN(LAMPORTS_PER_SOL * 25) },
+      ],
+    ]) {
+      const challengeIns = await workspace.instructionBuilder.createChallenge({
+        challengeId: challengeId as string,
+        rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+        minDeposit: challengeInfo.minDeposit,
+        joinDeadline: challengeInfo.endTime,
+        endTime: challengeInfo.endTime,
+        ...(caps as Record<string, BN>),
+        signer: challengeOwnerKeypair.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...challengeIns),
+        [challengeOwnerKeypair],
+      );
+    }
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[join_challenge] should: fail to join once the max players is reached', async () => {
+    const ins = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.maxPlayersId,
+      amount: challengeInfo.minDeposit,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player1,
+    ]);
+
+    await expectFailure(
+      await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.maxPlayersId,
+        amount: challengeInfo.minDeposit,
+        signer: player2.publicKey,
+      }),
+      player2,
+    );
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.maxPlayersId,
+    );
+    expect(state.players.length).eq(1);
+  });
+
+  it('[join_challenge] should: fail to deposit more than the max deposit per player', async () => {
+    const ins = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.maxDepositId,
+      amount: challengeInfo.minDeposit,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player1,
+    ]);
+
+    await expectFailure(
+      await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.maxDepositId,
+        amount: challengeInfo.minDeposit,
+        signer: player1.publicKey,
+      }),
+      player1,
+    );
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.maxDepositId,
+    );
+    expect(state.prizePool.eq(challengeInfo.minDeposit)).to.be.true;
+  });
+
+  it('[join_challenge] should: fail to join once the max prize pool would be exceeded', async () => {
+    for (const player of [player1, player2]) {
+      const ins = await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.maxPrizePoolId,
+        amount: challengeInfo.minDeposit,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+    }
+
+    await expectFailure(
+      await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.maxPrizePoolId,
+        amount: challengeInfo.minDeposit,
+        signer: player1.publicKey,
+      }),
+      player1,
+    );
+  });
+
+  it('[donate] should: fail to donate once the max prize pool would be exceeded', async () => {
+    await expectFailure(
+      await workspace.instructionBuilder.donateReward({
+        challengeId: challengeInfo.maxPrizePoolId,
+        amount: new BN(LAMPORTS_PER_SOL * 10),
+        signer: donor.publicKey,
+      }),
+      donor,
+    );
+
+    /**
+     * @dev Donations up to the cap are still accepted
+     */
+    const ins = await workspace.instructionBuilder.donateReward({
+      challengeId: challengeInfo.maxPrizePoolId,
+      amount: new BN(LAMPORTS_PER_SOL * 5),
+      signer: donor.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      donor,
+    ]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.maxPrizePoolId,
+    );
+    expect(state.prizePool.eq(new BN(LAMPORTS_PER_SOL * 25))).to.be.true;
+  });
+});
@@ -16,6 +16,7 @@ import { SendTransactionError } from '@solana/web3.js';
  */
 import { Challenge } from '../client/challenge.idl';
 import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
 
 /**
  * @dev Initialize provider to get wallet provider, cluster node and program id.
@@ -55,6 +56,8 @@ describe('[challenge_participation]', async () => {
     id: Keypair.generate().publicKey.toBase58().slice(0, 10),
     minDeposit: new BN(LAMPORTS_PER_SOL * 10),
     rewardTokenMintAccount: null,
+    joinDeadline: null,
+    endTime: null,
   };
 
   /**
@@ -195,6 +198,7 @@ describe('[challenge_participation]', async () => {
      */
     const ins = await workspace.instructionBuilder.updateChallengeRegistry({
       allowedAdministrators: [administrator.publicKey],
+      correctionWindow: new BN(60 * 60),
       signer: workspace.provider.publicKey,
     });
     await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
@@ -224,6 +228,12 @@ describe('[challenge_participation]', async () => {
       ).address[0],
     );
 
+    /**
+     * @dev Players have a short window to join before the challenge ends
+     */
+    challengeInfo.joinDeadline = new BN(Math.floor(Date.now() / 1000) + 20);
+    challengeInfo.endTime = challengeInfo.joinDeadline;
+
     /**
      * @dev Build instruction
      */
@@ -231,6 +241,8 @@ describe('[challenge_participation]', async () => {
       challengeId: challengeInfo.id,
       rewardMintAddress: challengeInfo.rewardTokenMintAccount,
       minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
       signer: challengeOwnerKeypair.publicKey,
     });
 
@@ -452,6 +464,224 @@ describe('[challenge_participation]', async () => {
     expect(state.donatePool.eq(new BN(LAMPORTS_PER_SOL * 4))).to.be.true;
   });
 
+  it('[submit_proof] should: non-participants fail to submit a proof', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.submitProof({
+      challengeId: challengeInfo.id,
+      evidenceUri: 'https://example.com/proof/donor',
+      contentHash: new Array(32).fill(1),
+      signer: donor.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        donor,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[submit_proof] should: players can submit a proof', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.submitProof({
+      challengeId: challengeInfo.id,
+      evidenceUri: 'https://example.com/proof/player1',
+      contentHash: new Array(32).fill(1),
+      signer: player1.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player1,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getSubmission(
+      challengeInfo.id,
+      player1.publicKey,
+    );
+    expect(state.player.toBase58()).eq(player1.publicKey.toBase58());
+    expect(state.evidenceUri).eq('https://example.com/proof/player1');
+    expect(!!(state.status as any).pending).to.be.true;
+  });
+
+  it('[review_proof] should: other people than administrators/challenge owner fail to review a proof', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.acceptProof({
+      challengeId: challengeInfo.id,
+      player: player1.publicKey,
+      signer: player2.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player2,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[review_proof] should: challenge owner can accept a proof only once', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.acceptProof({
+      challengeId: challengeInfo.id,
+      player: player1.publicKey,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getSubmission(
+      challengeInfo.id,
+      player1.publicKey,
+    );
+    expect(!!(state.status as any).accepted).to.be.true;
+    expect(state.reviewer.toBase58()).eq(
+      challengeOwnerKeypair.publicKey.toBase58(),
+    );
+
+    /**
+     * @dev Reviewing again must fail
+     */
+    const rejectIns = await workspace.instructionBuilder.rejectProof({
+      challengeId: challengeInfo.id,
+      player: player1.publicKey,
+      signer: administrator.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...rejectIns),
+        [administrator],
+      );
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[transition_challenge_status] should: fail to transition while the challenge is in its scheduled phase', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.transitionChallengeStatus({
+      challengeId: challengeInfo.id,
+      signer: donor.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        donor,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[finalize_pool] should: fail to submit winner list before the challenge ends', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player1.publicKey, player2.publicKey],
+      signer: administrator.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[join_challenge] should: cannot join the challenge after the join deadline', async () => {
+    /**
+     * @dev Wait until joining is closed
+     */
+    await sleepUntil(challengeInfo.joinDeadline);
+
+    const player1Ins = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: new BN(LAMPORTS_PER_SOL * 10),
+      signer: player1.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...player1Ins),
+        [player1],
+      );
+
+      throw new Error('Should failed here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[transition_challenge_status] should: anyone can move the challenge into its running phase', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.transitionChallengeStatus({
+      challengeId: challengeInfo.id,
+      signer: donor.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      donor,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).running).to.be.true;
+  });
+
   it('[finalize_pool] should: other people than administrators/challenge owner fail to finalize the pool', async () => {
     /**
      * @dev Build instruction
@@ -539,9 +769,9 @@ describe('[challenge_participation]', async () => {
     const state = await workspace.challengeState.getChallenge(challengeInfo.id);
 
     /**
-     * @dev Challenge status changed to Finalized
+     * @dev Challenge status changed to PendingResult
      */
-    expect(!!(state.status as any).finalized).to.be.true;
+    expect(!!(state.status as any).pendingResult).to.be.true;
 
     /**
      * @dev We have a winner list here
@@ -552,6 +782,90 @@ describe('[challenge_participation]', async () => {
     expect(winners[1].publicKey.toBase58()).eq(player2.publicKey.toBase58());
   });
 
+  it('[amend_winner_list] should: other people than administrators/challenge owner fail to amend the winner list', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player3.publicKey],
+      signer: player3.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player3,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[amend_winner_list] should: challenge owner can amend the winner list before the first claim', async () => {
+    /**
+     * @dev Replace the winner list with a mistaken entry
+     */
+    const ins = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player1.publicKey, player3.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    let state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    let winners = (state.players as any).filter((player) => player.isWinner);
+    expect(winners.length).eq(2);
+    expect(winners[1].publicKey.toBase58()).eq(player3.publicKey.toBase58());
+
+    /**
+     * @dev Correct the entry back
+     */
+    const correctionIns = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player1.publicKey, player2.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...correctionIns),
+      [challengeOwnerKeypair],
+    );
+
+    state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    winners = (state.players as any).filter((player) => player.isWinner);
+    expect(winners.length).eq(2);
+    expect(winners[0].publicKey.toBase58()).eq(player1.publicKey.toBase58());
+    expect(winners[1].publicKey.toBase58()).eq(player2.publicKey.toBase58());
+  });
+
+  it('[open_dispute] should: non-participants fail to dispute the winner list', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.openDispute({
+      challengeId: challengeInfo.id,
+      reasonUri: 'https://example.com/dispute',
+      signer: donor.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        donor,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
   it('[claim_reward] should: non-winners fail to claim reward', async () => {
     /**
      * @dev Player 1 claim reward
@@ -649,4 +963,27 @@ describe('[challenge_participation]', async () => {
     expect(Number(player1TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 107);
     expect(Number(player2TokenAccount.amount)).eq(LAMPORTS_PER_SOL * 107);
   });
+
+  it('[amend_winner_list] should: fail to amend the winner list after rewards were claimed', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player3.publicKey],
+      signer: administrator.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
 });
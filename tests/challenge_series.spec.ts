@@ -0,0 +1,292 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import { createMint } from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[challenge_series]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const seriesOwnerKeypair = Keypair.generate();
+  const otherPerson = Keypair.generate();
+  const administrator = Keypair.generate();
+
+  /**
+   * @dev Initialize series info
+   */
+  const seriesInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 6),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    await workspace.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(
+        seriesOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      ),
+    );
+    await workspace.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(
+        otherPerson.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      ),
+    );
+    await workspace.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(
+        administrator.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      ),
+    );
+
+    /**
+     * @dev Create a mint account
+     */
+    seriesInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      seriesOwnerKeypair,
+      seriesOwnerKeypair.publicKey,
+      seriesOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: seriesInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[create_challenge_series] should: anyone can create a series publicly', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.createChallengeSeries({
+      seriesId: seriesInfo.id,
+      minDeposit: seriesInfo.minDeposit,
+      rewardMintAddress: seriesInfo.rewardTokenMintAccount,
+      joinDuration: new BN(60 * 60),
+      duration: new BN(60 * 60 * 24 * 7),
+      rolloverEnabled: true,
+      signer: seriesOwnerKeypair.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      seriesOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallengeSeries(
+      seriesInfo.id,
+    );
+
+    expect(state.id).eq(seriesInfo.id);
+    expect(state.owner.toBase58()).eq(
+      seriesOwnerKeypair.publicKey.toBase58(),
+    );
+    expect(state.round.eq(new BN(0))).to.be.true;
+    expect(state.rolloverEnabled).to.be.true;
+  });
+
+  it('[spawn_series_round] should: outsider cannot spawn a round', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.spawnSeriesRound({
+      seriesId: seriesInfo.id,
+      signer: otherPerson.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        otherPerson,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[spawn_series_round] should: series owner can spawn the next round', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.spawnSeriesRound({
+      seriesId: seriesInfo.id,
+      signer: seriesOwnerKeypair.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      seriesOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const series = await workspace.challengeState.getChallengeSeries(
+      seriesInfo.id,
+    );
+    expect(series.round.eq(new BN(1))).to.be.true;
+
+    const state = await workspace.challengeState.getChallenge(
+      `${seriesInfo.id}-1`,
+    );
+    expect(state.id).eq(`${seriesInfo.id}-1`);
+    expect(state.round.eq(new BN(1))).to.be.true;
+    expect(state.minDeposit.eq(seriesInfo.minDeposit)).to.be.true;
+    // @ts-ignore
+    expect(!!state.status.open).to.be.true;
+
+    /**
+     * @dev Zero-winner rounds roll over into the next round only
+     */
+    const {
+      address: [nextRoundPubkey],
+    } = await workspace.programFinder.getChallengeAccount(`${seriesInfo.id}-2`);
+    expect(!!(state.noWinnerPolicy as any).rollOver).to.be.true;
+    expect(state.noWinnerRolloverTarget.toBase58()).eq(
+      nextRoundPubkey.toBase58(),
+    );
+  });
+
+  it('[create_challenge] should: outsider cannot take the id of the next round', async () => {
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId: `${seriesInfo.id}-2`,
+      rewardMintAddress: seriesInfo.rewardTokenMintAccount,
+      minDeposit: seriesInfo.minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      signer: otherPerson.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        otherPerson,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    /**
+     * @dev The series can still advance
+     */
+    const spawnIns = await workspace.instructionBuilder.spawnSeriesRound({
+      seriesId: seriesInfo.id,
+      signer: seriesOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...spawnIns),
+      [seriesOwnerKeypair],
+    );
+
+    const series = await workspace.challengeState.getChallengeSeries(
+      seriesInfo.id,
+    );
+    expect(series.round.eq(new BN(2))).to.be.true;
+  });
+
+  it('[create_challenge] should: anyone can create a challenge whose id ends with a number outside of any series', async () => {
+    const challengeId = `${Keypair.generate().publicKey.toBase58().slice(0, 8)}-2026`;
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: seriesInfo.rewardTokenMintAccount,
+      minDeposit: seriesInfo.minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      signer: otherPerson.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      otherPerson,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeId);
+    expect(state.id).eq(challengeId);
+    expect(state.owner.toBase58()).eq(otherPerson.publicKey.toBase58());
+  });
+});
@@ -0,0 +1,213 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import { createMint } from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[challenge_status]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const otherPerson = Keypair.generate();
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    joinDeadline: null,
+    startTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    endTime: new BN(Math.floor(Date.now() / 1000) + 2 * 60 * 60),
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      otherPerson,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev Joining closes shortly, the challenge starts later
+     */
+    challengeInfo.joinDeadline = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      startTime: challengeInfo.startTime,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[transition_challenge_status] should: the status change is emitted when the challenge moves into its upcoming phase', async () => {
+    await sleepUntil(challengeInfo.joinDeadline);
+
+    /**
+     * @dev Listen to the status changes
+     */
+    const events = [];
+    const listener = program.addEventListener(
+      'ChallengeStatusChanged',
+      (event) => events.push(event),
+    );
+
+    const ins = await workspace.instructionBuilder.transitionChallengeStatus({
+      challengeId: challengeInfo.id,
+      signer: otherPerson.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      otherPerson,
+    ]);
+
+    /**
+     * @dev Wait for the logs to be delivered
+     */
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await program.removeEventListener(listener);
+
+    /**
+     * @dev Expect event
+     */
+    const {
+      address: [challengePubkey],
+    } = await workspace.programFinder.getChallengeAccount(challengeInfo.id);
+    const event = events.find((item) => item.id === challengeInfo.id);
+    expect(event.actor.toBase58()).eq(otherPerson.publicKey.toBase58());
+    expect(event.challengeKey.toBase58()).eq(challengePubkey.toBase58());
+    expect(!!event.from.open).to.be.true;
+    expect(!!event.to.upcoming).to.be.true;
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).upcoming).to.be.true;
+  });
+
+  it('[transition_challenge_status] should: fail to move an upcoming challenge back to open', async () => {
+    /**
+     * @dev The owner reopens joining, so the scheduled phase is open again
+     */
+    const updateIns = await workspace.instructionBuilder.updateChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: new BN(Math.floor(Date.now() / 1000) + 30 * 60),
+      startTime: challengeInfo.startTime,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...updateIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.transitionChallengeStatus({
+      challengeId: challengeInfo.id,
+      signer: otherPerson.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        otherPerson,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+      expect(
+        (e as SendTransactionError).logs.some((log) =>
+          log.includes('StatusTransitionIsNotAllowed'),
+        ),
+      ).to.be.true;
+    }
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).upcoming).to.be.true;
+  });
+});
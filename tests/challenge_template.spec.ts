@@ -0,0 +1,320 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import { createMint } from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[challenge_template]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const otherPerson = Keypair.generate();
+  const administrator = Keypair.generate();
+  const templatedChallengeId = Keypair.generate()
+    .publicKey.toBase58()
+    .slice(0, 10);
+
+  /**
+   * @dev Initialize template info
+   */
+  const templateInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    maxDeposit: new BN(LAMPORTS_PER_SOL * 50),
+    minDuration: new BN(60 * 60),
+    maxDuration: new BN(60 * 60 * 24 * 7),
+    feeBps: 500,
+    rewardTokenMintAccount: null,
+  };
+
+  /**
+   * @dev Build the creator settings of a challenge ending in a day
+   */
+  const buildChallengePayload = (challengeId: string, minDeposit: BN) => {
+    const now = Math.floor(Date.now() / 1000);
+    return {
+      templateId: templateInfo.id,
+      challengeId,
+      rewardMintAddress: templateInfo.rewardTokenMintAccount,
+      minDeposit,
+      joinDeadline: new BN(now + 60 * 60),
+      endTime: new BN(now + 60 * 60 * 24),
+      signer: challengeOwnerKeypair.publicKey,
+    };
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    await workspace.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      ),
+    );
+    await workspace.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(
+        otherPerson.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      ),
+    );
+    await workspace.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(
+        administrator.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      ),
+    );
+
+    /**
+     * @dev Create a mint account
+     */
+    templateInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: templateInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[create_challenge_template] should: non-administrator fail to create a template', async () => {
+    const ins = await workspace.instructionBuilder.createChallengeTemplate({
+      templateId: templateInfo.id,
+      rewardMintAddress: templateInfo.rewardTokenMintAccount,
+      minDeposit: templateInfo.minDeposit,
+      maxDeposit: templateInfo.maxDeposit,
+      minDuration: templateInfo.minDuration,
+      maxDuration: templateInfo.maxDuration,
+      feeBps: templateInfo.feeBps,
+      signer: otherPerson.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        otherPerson,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[create_challenge_template] should: administrator can create a template', async () => {
+    const ins = await workspace.instructionBuilder.createChallengeTemplate({
+      templateId: templateInfo.id,
+      rewardMintAddress: templateInfo.rewardTokenMintAccount,
+      minDeposit: templateInfo.minDeposit,
+      maxDeposit: templateInfo.maxDeposit,
+      minDuration: templateInfo.minDuration,
+      maxDuration: templateInfo.maxDuration,
+      feeBps: templateInfo.feeBps,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallengeTemplate(
+      templateInfo.id,
+    );
+    expect(state.isEnabled).to.be.true;
+    expect(state.feeBps).eq(templateInfo.feeBps);
+    expect(state.maxDeposit.eq(templateInfo.maxDeposit)).to.be.true;
+  });
+
+  it('[create_challenge_from_template] should: fail to create a challenge outside of the template deposit range', async () => {
+    const ins = await workspace.instructionBuilder.createChallengeFromTemplate(
+      buildChallengePayload(
+        Keypair.generate().publicKey.toBase58().slice(0, 10),
+        new BN(LAMPORTS_PER_SOL * 100),
+      ),
+    );
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[create_challenge_from_template] should: anyone can create a challenge within the template', async () => {
+    const ins = await workspace.instructionBuilder.createChallengeFromTemplate(
+      buildChallengePayload(templatedChallengeId, templateInfo.minDeposit),
+    );
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(
+      templatedChallengeId,
+    );
+    const { address } =
+      await workspace.programFinder.getChallengeTemplateAccount(
+        templateInfo.id,
+      );
+    expect(state.feeBps).eq(templateInfo.feeBps);
+    expect(state.template.toBase58()).eq(address[0].toBase58());
+    expect(state.maxDepositPerPlayer.eq(templateInfo.maxDeposit)).to.be.true;
+  });
+
+  it('[update_challenge] should: fail to update a templated challenge without its template', async () => {
+    const ins = await workspace.instructionBuilder.updateChallenge(
+      buildChallengePayload(
+        templatedChallengeId,
+        new BN(LAMPORTS_PER_SOL * 100),
+      ),
+    );
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[update_challenge_from_template] should: fail to update a challenge outside of the template deposit range', async () => {
+    const ins = await workspace.instructionBuilder.updateChallengeFromTemplate(
+      buildChallengePayload(
+        templatedChallengeId,
+        new BN(LAMPORTS_PER_SOL * 100),
+      ),
+    );
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[update_challenge_from_template] should: owner can update a challenge within the template', async () => {
+    const ins = await workspace.instructionBuilder.updateChallengeFromTemplate({
+      ...buildChallengePayload(
+        templatedChallengeId,
+        new BN(LAMPORTS_PER_SOL * 20),
+      ),
+      payoutModel: { stakeProportional: {} },
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state, the payout model and fee stay pinned to the template
+     */
+    const state = await workspace.challengeState.getChallenge(
+      templatedChallengeId,
+    );
+    expect(state.minDeposit.eq(new BN(LAMPORTS_PER_SOL * 20))).to.be.true;
+    expect(Object.keys(state.payoutModel)[0]).eq('equalSplit');
+    expect(state.feeBps).eq(templateInfo.feeBps);
+    expect(state.maxDepositPerPlayer.eq(templateInfo.maxDeposit)).to.be.true;
+  });
+
+  it('[update_challenge_template] should: fail to create a challenge from a disabled template', async () => {
+    const updateIns =
+      await workspace.instructionBuilder.updateChallengeTemplate({
+        templateId: templateInfo.id,
+        isEnabled: false,
+        rewardMintAddress: templateInfo.rewardTokenMintAccount,
+        minDeposit: templateInfo.minDeposit,
+        maxDeposit: templateInfo.maxDeposit,
+        minDuration: templateInfo.minDuration,
+        maxDuration: templateInfo.maxDuration,
+        feeBps: templateInfo.feeBps,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...updateIns),
+      [administrator],
+    );
+
+    const ins = await workspace.instructionBuilder.createChallengeFromTemplate(
+      buildChallengePayload(
+        Keypair.generate().publicKey.toBase58().slice(0, 10),
+        templateInfo.minDeposit,
+      ),
+    );
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+});
@@ -0,0 +1,474 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[close_challenge]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  let playerTokenAccount: Account;
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    disputedId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    joinDeadline: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    endTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    disputedEndTime: null,
+  };
+
+  /**
+   * @dev Create a challenge and let the player join it
+   */
+  const createAndJoinChallenge = async (
+    challengeId: string,
+    endTime: BN,
+  ) => {
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId,
+      amount: challengeInfo.minDeposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+  };
+
+  /**
+   * @dev Submit the player as the winner and dispute the result
+   */
+  const submitAndDisputeResult = async (challengeId: string) => {
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId,
+      winnerList: [player.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const disputeIns = await workspace.instructionBuilder.openDispute({
+      challengeId,
+      reasonUri: 'https://example.com/dispute',
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...disputeIns),
+      [player],
+    );
+  };
+
+  /**
+   * @dev Admin force cancels the challenge and the player withdraws the deposit
+   */
+  const cancelAndWithdraw = async (challengeId: string) => {
+    const cancelIns = await workspace.instructionBuilder.forceCancelChallenge({
+      challengeId,
+      reasonCode: 1,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...cancelIns),
+      [administrator],
+    );
+
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId,
+        signer: player.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [player],
+    );
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Add admin and keep submitted results disputable for a while
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      disputeWindow: new BN(60),
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The player joins both challenges and submits a proof to the first one
+     */
+    await createAndJoinChallenge(challengeInfo.id, challengeInfo.endTime);
+
+    challengeInfo.disputedEndTime = new BN(Math.floor(Date.now() / 1000) + 10);
+    await createAndJoinChallenge(
+      challengeInfo.disputedId,
+      challengeInfo.disputedEndTime,
+    );
+
+    const proofIns = await workspace.instructionBuilder.submitProof({
+      challengeId: challengeInfo.id,
+      evidenceUri: 'https://example.com/proof/player',
+      contentHash: new Array(32).fill(1),
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...proofIns),
+      [player],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[close_challenge] should: fail to close a challenge with outstanding liabilities', async () => {
+    const ins = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[close_submission] should: fail to close a proof submission before the challenge is settled', async () => {
+    const ins = await workspace.instructionBuilder.closeSubmission({
+      challengeId: challengeInfo.id,
+      player: player.publicKey,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[close_challenge] should: outsider cannot close the settled challenge', async () => {
+    await cancelAndWithdraw(challengeInfo.id);
+
+    const ins = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[close_challenge] should: fail to close the settled challenge before its proof submissions are closed', async () => {
+    const ins = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[close_submission] should: anyone can close the proof submission and the rent goes back to the player', async () => {
+    const {
+      address: [submissionPubkey],
+    } = await workspace.programFinder.getSubmissionAccount(
+      challengeInfo.id,
+      player.publicKey,
+    );
+    const playerBalanceBefore = await workspace.connection.getBalance(
+      player.publicKey,
+    );
+
+    const ins = await workspace.instructionBuilder.closeSubmission({
+      challengeId: challengeInfo.id,
+      player: player.publicKey,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect the account is gone and the rent is returned
+     */
+    const accountInfo = await workspace.connection.getAccountInfo(
+      submissionPubkey,
+    );
+    expect(accountInfo).to.be.null;
+
+    const playerBalanceAfter = await workspace.connection.getBalance(
+      player.publicKey,
+    );
+    expect(playerBalanceAfter > playerBalanceBefore).to.be.true;
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.totalSubmissions.eq(new BN(0))).to.be.true;
+  });
+
+  it('[close_challenge] should: challenge owner closes the settled challenge and reclaims the rent', async () => {
+    const {
+      address: [challengePubkey],
+    } = await workspace.programFinder.getChallengeAccount(challengeInfo.id);
+    const ownerBalanceBefore = await workspace.connection.getBalance(
+      challengeOwnerKeypair.publicKey,
+    );
+
+    const ins = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect the account is gone and the rent is returned
+     */
+    const accountInfo = await workspace.connection.getAccountInfo(
+      challengePubkey,
+    );
+    expect(accountInfo).to.be.null;
+
+    const ownerBalanceAfter = await workspace.connection.getBalance(
+      challengeOwnerKeypair.publicKey,
+    );
+    expect(ownerBalanceAfter > ownerBalanceBefore).to.be.true;
+  });
+
+  it('[submit_proof] should: players can submit a proof to a challenge recreated with the same id', async () => {
+    await createAndJoinChallenge(challengeInfo.id, challengeInfo.endTime);
+
+    const ins = await workspace.instructionBuilder.submitProof({
+      challengeId: challengeInfo.id,
+      evidenceUri: 'https://example.com/proof/player/recreated',
+      contentHash: new Array(32).fill(2),
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    const state = await workspace.challengeState.getSubmission(
+      challengeInfo.id,
+      player.publicKey,
+    );
+    expect(state.evidenceUri).eq('https://example.com/proof/player/recreated');
+  });
+
+  it('[close_dispute] should: fail to close the dispute before the challenge is settled', async () => {
+    await sleepUntil(challengeInfo.disputedEndTime);
+    await submitAndDisputeResult(challengeInfo.disputedId);
+
+    const ins = await workspace.instructionBuilder.closeDispute({
+      challengeId: challengeInfo.disputedId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[close_challenge] should: fail to close the settled challenge before its dispute is closed', async () => {
+    await cancelAndWithdraw(challengeInfo.disputedId);
+
+    const ins = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.disputedId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[close_dispute] should: anyone can close the dispute so the challenge can be closed', async () => {
+    const {
+      address: [disputePubkey],
+    } = await workspace.programFinder.getDisputeAccount(
+      challengeInfo.disputedId,
+    );
+
+    const closeDisputeIns = await workspace.instructionBuilder.closeDispute({
+      challengeId: challengeInfo.disputedId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    const closeChallengeIns =
+      await workspace.instructionBuilder.closeChallenge({
+        challengeId: challengeInfo.disputedId,
+        signer: challengeOwnerKeypair.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...closeDisputeIns, ...closeChallengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    /**
+     * @dev Expect the dispute is gone with the challenge
+     */
+    const accountInfo = await workspace.connection.getAccountInfo(
+      disputePubkey,
+    );
+    expect(accountInfo).to.be.null;
+  });
+
+  it('[open_dispute] should: players can dispute the result of a challenge recreated with the same id', async () => {
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+    await createAndJoinChallenge(challengeInfo.disputedId, endTime);
+    await sleepUntil(endTime);
+    await submitAndDisputeResult(challengeInfo.disputedId);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.disputedId,
+    );
+    expect(!!(state.status as any).disputed).to.be.true;
+    expect(state.isDisputeOpened).to.be.true;
+  });
+});
@@ -134,4 +134,42 @@ describe('[create_token_vault]', async () => {
       ),
     );
   });
+
+  it('[create_treasury] should: the treasury is created along with the token vault', async () => {
+    const ins = await workspace.instructionBuilder.createTreasury({
+      mintTokenAddress: mintTokenAccount.toBase58(),
+      signer: workspace.provider.publicKey,
+    });
+    expect(ins.length).eq(0);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getTreasury(
+      mintTokenAccount.toBase58(),
+    );
+    expect(state.mintAccount.toBase58()).eq(mintTokenAccount.toBase58());
+  });
+
+  it('[create_treasury] should: fail to create a treasury for a mint without token vault', async () => {
+    const otherMintTokenAccount = await createMint(
+      workspace.connection,
+      keypair,
+      keypair.publicKey,
+      keypair.publicKey,
+      9,
+    );
+
+    const ins = await workspace.instructionBuilder.createTreasury({
+      mintTokenAddress: otherMintTokenAccount.toBase58(),
+      signer: workspace.provider.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
 });
@@ -0,0 +1,301 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[creator_fee]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  let playerTokenAccount: Account;
+  let challengeOwnerTokenAccount: Account;
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Add admin and allow creator fees up to 5%
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      maxCreatorFeeBps: 500,
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The challenge owner token account receives the creator fee
+     */
+    challengeOwnerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      challengeOwnerKeypair.publicKey,
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[create_challenge] should: fail to create a challenge with a creator fee above the registry max', async () => {
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      creatorFeeBps: 600,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[create_challenge] should: anyone can create a challenge with a creator fee', async () => {
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.endTime,
+      endTime: challengeInfo.endTime,
+      creatorFeeBps: 500,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: challengeInfo.minDeposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.creatorFeeBps).eq(500);
+  });
+
+  it('[claim_creator_fee] should: fail to claim the creator fee before the result is final', async () => {
+    const ins = await workspace.instructionBuilder.claimCreatorFee({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[claim_reward] should: the creator fee is taken from the prize pool once the result is final', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.creatorFee.eq(new BN(LAMPORTS_PER_SOL / 2))).to.be.true;
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 99.5);
+  });
+
+  it('[claim_creator_fee] should: outsider cannot claim the creator fee', async () => {
+    const ins = await workspace.instructionBuilder.claimCreatorFee({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[claim_creator_fee] should: challenge owner claims the creator fee once', async () => {
+    const ins = await workspace.instructionBuilder.claimCreatorFee({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    challengeOwnerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      challengeOwnerKeypair.publicKey,
+    );
+    expect(Number(challengeOwnerTokenAccount.amount)).eq(LAMPORTS_PER_SOL / 2);
+
+    /**
+     * @dev The creator fee cannot be claimed twice
+     */
+    const ins2 = await workspace.instructionBuilder.claimCreatorFee({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins2), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+});
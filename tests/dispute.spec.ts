@@ -0,0 +1,449 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[dispute]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player1 = Keypair.generate();
+  const player2 = Keypair.generate();
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    upheldId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    replacedId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    amendedId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+  };
+
+  /**
+   * @dev Get the token balance of a player
+   */
+  const getBalance = async (player: Keypair) => {
+    const tokenAccount: Account = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    return Number(tokenAccount.amount);
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player1,
+      player2,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players
+     */
+    for (const player of [player1, player2]) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        playerTokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin and keep submitted results disputable for a while
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      disputeWindow: new BN(60),
+      correctionWindow: new BN(600),
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The challenges end shortly and both players join all of them
+     */
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    for (const challengeId of [
+      challengeInfo.upheldId,
+      challengeInfo.replacedId,
+      challengeInfo.amendedId,
+    ]) {
+      const challengeIns = await workspace.instructionBuilder.createChallenge({
+        challengeId,
+        rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+        minDeposit: challengeInfo.minDeposit,
+        joinDeadline: challengeInfo.endTime,
+        endTime: challengeInfo.endTime,
+        signer: challengeOwnerKeypair.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...challengeIns),
+        [challengeOwnerKeypair],
+      );
+
+      for (const player of [player1, player2]) {
+        const joinIns = await workspace.instructionBuilder.joinChallenge({
+          challengeId,
+          amount: challengeInfo.minDeposit,
+          signer: player.publicKey,
+        });
+        await workspace.provider.sendAndConfirm(
+          new Transaction().add(...joinIns),
+          [player],
+        );
+      }
+    }
+
+    await sleepUntil(challengeInfo.endTime);
+
+    /**
+     * @dev Owner submits player 1 as the only winner of all challenges
+     */
+    for (const challengeId of [
+      challengeInfo.upheldId,
+      challengeInfo.replacedId,
+      challengeInfo.amendedId,
+    ]) {
+      const submitIns = await workspace.instructionBuilder.submitWinnerList({
+        challengeId,
+        winnerList: [player1.publicKey],
+        signer: challengeOwnerKeypair.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...submitIns),
+        [challengeOwnerKeypair],
+      );
+    }
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[claim_reward] should: winners cannot claim while the result is disputable', async () => {
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.upheldId,
+      signer: player1.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player1,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[amend_winner_list] should: amending a pending result reopens the dispute window', async () => {
+    const previousState = await workspace.challengeState.getChallenge(
+      challengeInfo.amendedId,
+    );
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const ins = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.amendedId,
+      winnerList: [player2.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.amendedId,
+    );
+    expect(state.disputeDeadline.gt(previousState.disputeDeadline)).to.be.true;
+
+    /**
+     * @dev The amended winner cannot claim before it can be disputed
+     */
+    const claimIns = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.amendedId,
+      signer: player2.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...claimIns),
+        [player2],
+      );
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[open_dispute] should: participants can dispute the winner list within the window', async () => {
+    for (const challengeId of [
+      challengeInfo.upheldId,
+      challengeInfo.replacedId,
+    ]) {
+      const ins = await workspace.instructionBuilder.openDispute({
+        challengeId,
+        reasonUri: 'https://example.com/dispute',
+        signer: player2.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player2,
+      ]);
+
+      const state = await workspace.challengeState.getChallenge(challengeId);
+      expect(!!(state.status as any).disputed).to.be.true;
+    }
+  });
+
+  it('[claim_reward] should: winners cannot claim while the winner list is disputed', async () => {
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.upheldId,
+      signer: player1.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player1,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[resolve_dispute] should: other people than administrators fail to resolve the dispute', async () => {
+    const ins = await workspace.instructionBuilder.upholdWinnerList({
+      challengeId: challengeInfo.upheldId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[resolve_dispute] should: administrator can uphold the winner list', async () => {
+    const ins = await workspace.instructionBuilder.upholdWinnerList({
+      challengeId: challengeInfo.upheldId,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.upheldId,
+    );
+    expect(!!(state.status as any).finalized).to.be.true;
+
+    /**
+     * @dev The upheld winner claims the whole prize pool
+     */
+    const balance = await getBalance(player1);
+    const claimIns = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.upheldId,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...claimIns),
+      [player1],
+    );
+    expect((await getBalance(player1)) - balance).eq(LAMPORTS_PER_SOL * 20);
+  });
+
+  it('[resolve_dispute] should: administrator can replace the winner list', async () => {
+    /**
+     * @dev Listen to the finalized results
+     */
+    const events = [];
+    const listener = program.addEventListener('ChallengeFinalized', (event) =>
+      events.push(event),
+    );
+
+    const ins = await workspace.instructionBuilder.replaceWinnerList({
+      challengeId: challengeInfo.replacedId,
+      winnerList: [player2.publicKey],
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    /**
+     * @dev Wait for the logs to be delivered
+     */
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await program.removeEventListener(listener);
+
+    const {
+      address: [challengePubkey],
+    } = await workspace.programFinder.getChallengeAccount(
+      challengeInfo.replacedId,
+    );
+    const event = events.find((item) => item.id === challengeInfo.replacedId);
+    expect(event.actor.toBase58()).eq(administrator.publicKey.toBase58());
+    expect(event.challengeKey.toBase58()).eq(challengePubkey.toBase58());
+    expect(!!event.status.finalized).to.be.true;
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.replacedId,
+    );
+    expect(!!(state.status as any).finalized).to.be.true;
+    const winners = (state.players as any).filter((player) => player.isWinner);
+    expect(winners.length).eq(1);
+    expect(winners[0].publicKey.toBase58()).eq(player2.publicKey.toBase58());
+  });
+
+  it('[amend_winner_list] should: only administrators can amend the winner list after a resolved dispute', async () => {
+    const ins = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.replacedId,
+      winnerList: [player1.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    const adminIns = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.replacedId,
+      winnerList: [player2.publicKey],
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...adminIns),
+      [administrator],
+    );
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.replacedId,
+    );
+    const winners = (state.players as any).filter((player) => player.isWinner);
+    expect(winners.length).eq(1);
+    expect(winners[0].publicKey.toBase58()).eq(player2.publicKey.toBase58());
+  });
+
+  it('[claim_reward] should: only the replaced winner list can claim', async () => {
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.replacedId,
+      signer: player1.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player1,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    const balance = await getBalance(player2);
+    const claimIns = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.replacedId,
+      signer: player2.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...claimIns),
+      [player2],
+    );
+    expect((await getBalance(player2)) - balance).eq(LAMPORTS_PER_SOL * 20);
+  });
+});
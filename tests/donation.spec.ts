@@ -0,0 +1,365 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[donation]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  let playerTokenAccount: Account;
+  const donor = Keypair.generate();
+  let donorTokenAccount: Account;
+  const sweptDonor = Keypair.generate();
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    joinDeadline: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    endTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+      donor,
+      sweptDonor,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Mint to donors
+     */
+    for (const keypair of [donor, sweptDonor]) {
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        keypair,
+        challengeInfo.rewardTokenMintAccount,
+        keypair.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        tokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev Create the challenge and let the player join
+     */
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: challengeInfo.minDeposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+
+    const donateIns = await workspace.instructionBuilder.donateReward({
+      challengeId: challengeInfo.id,
+      amount: new BN(LAMPORTS_PER_SOL * 3),
+      signer: sweptDonor.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...donateIns),
+      [sweptDonor],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[refund_donation] should: fail to refund the donation before the challenge is canceled', async () => {
+    const ins = await workspace.instructionBuilder.refundDonation({
+      challengeId: challengeInfo.id,
+      signer: sweptDonor.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        sweptDonor,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[admin_withdraw_donate_pool] should: administrator sweeps the donations of a challenge in progress', async () => {
+    const ins = await workspace.instructionBuilder.adminWithdrawDonatePool({
+      challengeId: challengeInfo.id,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    /**
+     * @dev Expect state, the swept donations are no longer refundable
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.donatePool.eq(new BN(0))).to.be.true;
+    expect(state.prizePool.eq(challengeInfo.minDeposit)).to.be.true;
+    expect(state.donors[0].totalDonation.eq(new BN(0))).to.be.true;
+
+    const administratorTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      administrator,
+      challengeInfo.rewardTokenMintAccount,
+      administrator.publicKey,
+    );
+    expect(Number(administratorTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 3);
+  });
+
+  it('[donate_to_pool] should: other people can donate after the sweep', async () => {
+    const ins = await workspace.instructionBuilder.donateReward({
+      challengeId: challengeInfo.id,
+      amount: new BN(LAMPORTS_PER_SOL * 4),
+      signer: donor.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      donor,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.donatePool.eq(new BN(LAMPORTS_PER_SOL * 4))).to.be.true;
+  });
+
+  it('[cancel_challenge] should: challenge owner cancels the donated challenge', async () => {
+    const ins = await workspace.instructionBuilder.cancelChallenge({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).canceled).to.be.true;
+  });
+
+  it('[refund_donation] should: non-donors fail to refund a donation', async () => {
+    const ins = await workspace.instructionBuilder.refundDonation({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[admin_withdraw_donate_pool] should: administrator cannot sweep the donations donors can refund', async () => {
+    const ins = await workspace.instructionBuilder.adminWithdrawDonatePool({
+      challengeId: challengeInfo.id,
+      signer: administrator.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.donatePool.eq(new BN(LAMPORTS_PER_SOL * 4))).to.be.true;
+    expect(state.donors[1].isDonationRefunded).to.be.false;
+  });
+
+  it('[refund_donation] should: fail to refund a swept donation', async () => {
+    const ins = await workspace.instructionBuilder.refundDonation({
+      challengeId: challengeInfo.id,
+      signer: sweptDonor.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        sweptDonor,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[refund_donation] should: donor gets the donation back after the cancellation', async () => {
+    const ins = await workspace.instructionBuilder.refundDonation({
+      challengeId: challengeInfo.id,
+      signer: donor.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      donor,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.donatePool.eq(new BN(0))).to.be.true;
+    expect(state.donors[1].isDonationRefunded).to.be.true;
+
+    donorTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      donor,
+      challengeInfo.rewardTokenMintAccount,
+      donor.publicKey,
+    );
+    expect(Number(donorTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
+  });
+
+  it('[refund_donation] should: fail to refund the donation twice', async () => {
+    const ins = await workspace.instructionBuilder.refundDonation({
+      challengeId: challengeInfo.id,
+      signer: donor.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        donor,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+});
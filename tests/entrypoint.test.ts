@@ -2,4 +2,21 @@ require('./manage_challenge_registry.spec');
 require('./create_token_vault.spec');
 require('./manage_challenge.spec');
 require('./challenge_participation.spec');
+require('./challenge_status.spec');
+require('./challenge_caps.spec');
+require('./dispute.spec');
 require('./cancel_challenge_and_withdraw.spec');
+require('./donation.spec');
+require('./leave_challenge.spec');
+require('./challenge_series.spec');
+require('./milestone_challenge.spec');
+require('./no_winner_policy.spec');
+require('./challenge_template.spec');
+require('./force_cancel_challenge.spec');
+require('./close_challenge.spec');
+require('./ranked_payout.spec');
+require('./payout_model.spec');
+require('./payout_remainder.spec');
+require('./protocol_fee.spec');
+require('./creator_fee.spec');
+require('./referral.spec');
@@ -0,0 +1,231 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[force_cancel_challenge]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  let playerTokenAccount: Account;
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    joinDeadline: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    endTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev Create the challenge and let the player join
+     */
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: challengeInfo.minDeposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[force_cancel_challenge] should: challenge owner cannot force cancel the challenge', async () => {
+    const ins = await workspace.instructionBuilder.forceCancelChallenge({
+      challengeId: challengeInfo.id,
+      reasonCode: 1,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[force_cancel_challenge] should: administrator can force cancel the challenge with a reason', async () => {
+    const ins = await workspace.instructionBuilder.forceCancelChallenge({
+      challengeId: challengeInfo.id,
+      reasonCode: 2,
+      reasonUri: 'https://example.com/incident',
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).canceled).to.be.true;
+    expect(state.cancelReasonCode).eq(2);
+    expect(state.cancelReasonUri).eq('https://example.com/incident');
+  });
+
+  it('[force_cancel_challenge] should: fail to force cancel a canceled challenge', async () => {
+    const ins = await workspace.instructionBuilder.forceCancelChallenge({
+      challengeId: challengeInfo.id,
+      reasonCode: 3,
+      signer: administrator.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[withdraw_reward] should: players withdraw deposits after the force cancellation', async () => {
+    const ins = await workspace.instructionBuilder.withdrawDepositedReward({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
+  });
+});
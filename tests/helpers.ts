@@ -0,0 +1,11 @@
+import { BN } from '@project-serum/anchor';
+
+/**
+ * @dev Wait for the cluster clock to pass a timestamp
+ */
+export const sleepUntil = async (timestamp: BN) => {
+  const waitingTime = timestamp.toNumber() * 1000 - Date.now() + 2000;
+  await new Promise((resolve) =>
+    setTimeout(resolve, Math.max(waitingTime, 0)),
+  );
+};
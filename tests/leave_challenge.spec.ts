@@ -0,0 +1,288 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[leave_challenge]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player1 = Keypair.generate();
+  const player2 = Keypair.generate();
+  const outsider = Keypair.generate();
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    startTime: null,
+  };
+
+  /**
+   * @dev Get the token balance of an account owner
+   */
+  const getBalance = async (keypair: Keypair) => {
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      keypair,
+      challengeInfo.rewardTokenMintAccount,
+      keypair.publicKey,
+    );
+    return Number(tokenAccount.amount);
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player1,
+      player2,
+      outsider,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players and the outsider
+     */
+    for (const keypair of [player1, player2, outsider]) {
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        keypair,
+        challengeInfo.rewardTokenMintAccount,
+        keypair.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        tokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The challenge starts shortly and charges a 10% early exit penalty
+     */
+    challengeInfo.startTime = new BN(Math.floor(Date.now() / 1000) + 15);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.startTime,
+      startTime: challengeInfo.startTime,
+      endTime: challengeInfo.startTime.add(new BN(60 * 60)),
+      earlyExitPenaltyBps: 1000,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    for (const player of [player1, player2]) {
+      const joinIns = await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.id,
+        amount: challengeInfo.minDeposit,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...joinIns),
+        [player],
+      );
+    }
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[leave_challenge] should: non-players fail to leave the challenge', async () => {
+    const ins = await workspace.instructionBuilder.leaveChallenge({
+      challengeId: challengeInfo.id,
+      signer: outsider.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        outsider,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[leave_challenge] should: player leaves before the start and pays the early exit penalty', async () => {
+    const balance = await getBalance(player1);
+
+    const ins = await workspace.instructionBuilder.leaveChallenge({
+      challengeId: challengeInfo.id,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player1,
+    ]);
+
+    /**
+     * @dev The player gets 90% back and the penalty stays in the prize pool
+     */
+    expect((await getBalance(player1)) - balance).eq(LAMPORTS_PER_SOL * 9);
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.players.length).eq(1);
+    expect(state.prizePool.eq(new BN(LAMPORTS_PER_SOL * 11))).to.be.true;
+  });
+
+  it('[leave_challenge] should: fail to leave once the challenge has started', async () => {
+    await sleepUntil(challengeInfo.startTime);
+
+    const ins = await workspace.instructionBuilder.leaveChallenge({
+      challengeId: challengeInfo.id,
+      signer: player2.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player2,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.players.length).eq(1);
+  });
+
+  it('[close_challenge] should: the early exit penalty goes to the treasury once the canceled challenge is closed', async () => {
+    const cancelIns = await workspace.instructionBuilder.forceCancelChallenge({
+      challengeId: challengeInfo.id,
+      reasonCode: 1,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...cancelIns),
+      [administrator],
+    );
+
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId: challengeInfo.id,
+        signer: player2.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [player2],
+    );
+    expect(await getBalance(player2)).eq(LAMPORTS_PER_SOL * 100);
+
+    const treasuryBefore = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+
+    const closeIns = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.id,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...closeIns),
+      [challengeOwnerKeypair],
+    );
+
+    /**
+     * @dev Expect the penalty paid by player 1 is accounted in the treasury
+     */
+    const treasuryAfter = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(
+      treasuryAfter.balance.sub(treasuryBefore.balance).eq(
+        new BN(LAMPORTS_PER_SOL),
+      ),
+    ).to.be.true;
+  });
+});
@@ -13,7 +13,12 @@ import { expect } from 'chai';
  */
 import { Challenge } from '../client/challenge.idl';
 import { getWorkspace } from '../client';
-import { createMint } from '@solana/spl-token';
+import { sleepUntil } from './helpers';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
 
 /**
  * @dev Initialize provider to get wallet provider, cluster node and program id.
@@ -40,6 +45,8 @@ describe('[manage_challenge]', async () => {
     id: Keypair.generate().publicKey.toBase58().slice(0, 10),
     minDeposit: new BN(LAMPORTS_PER_SOL * 10),
     rewardTokenMintAccount: null,
+    joinDeadline: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+    endTime: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
   };
 
   /**
@@ -86,10 +93,11 @@ describe('[manage_challenge]', async () => {
     );
 
     /**
-     * @dev Add admin
+     * @dev Add admin, abandoned challenges expire shortly after they end
      */
     const ins = await workspace.instructionBuilder.updateChallengeRegistry({
       allowedAdministrators: [administrator.publicKey],
+      expiryGracePeriod: new BN(3),
       signer: workspace.provider.publicKey,
     });
     await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
@@ -106,6 +114,8 @@ describe('[manage_challenge]', async () => {
       challengeId: challengeInfo.id,
       rewardMintAddress: challengeInfo.rewardTokenMintAccount,
       minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
       signer: challengeOwnerKeypair.publicKey,
     });
 
@@ -149,6 +159,8 @@ describe('[manage_challenge]', async () => {
       challengeId: challengeInfo.id,
       rewardMintAddress: challengeInfo.rewardTokenMintAccount,
       minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
       signer: challengeOwnerKeypair.publicKey,
     });
 
@@ -178,13 +190,132 @@ describe('[manage_challenge]', async () => {
       challengeOwnerKeypair.publicKey.toBase58(),
     );
     // @ts-ignore
-    expect(!!state.status.created).to.be.true;
+    expect(!!state.status.open).to.be.true;
     // @ts-ignore
     expect(state.players.length).eq(0);
     expect(state.prizePool.eq(new BN(0))).to.be.true;
     expect(state.donatePool.eq(new BN(0))).to.be.true;
   });
 
+  it('[update_challenge_metadata] should: challenge owner can update the metadata before anyone joins', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeMetadata({
+      challengeId: challengeInfo.id,
+      metadata: {
+        title: 'Run 5km every day',
+        descriptionUri: 'https://example.com/challenge.json',
+        category: 'fitness',
+        rulesHash: new Array(32).fill(1),
+        coverImageUri: 'https://example.com/cover.png',
+      },
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const metadata = await workspace.challengeState.getChallengeMetadata(
+      challengeInfo.id,
+    );
+    expect(metadata.title).eq('Run 5km every day');
+    expect(metadata.category).eq('fitness');
+  });
+
+  it('[update_challenge] should: outsider cannot update the challenge', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.updateChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: new BN(LAMPORTS_PER_SOL),
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      signer: otherPerson.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        otherPerson,
+      ]);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[update_challenge] should: challenge owner can update the challenge before anyone joins', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.updateChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: new BN(LAMPORTS_PER_SOL),
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    /**
+     * @dev Send transaction
+     */
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.minDeposit.eq(new BN(LAMPORTS_PER_SOL))).to.be.true;
+  });
+
+  it('[expire_challenge] should: anyone cannot expire a running challenge', async () => {
+    /**
+     * @dev Build instruction
+     */
+    const ins = await workspace.instructionBuilder.expireChallenge({
+      challengeId: challengeInfo.id,
+      signer: otherPerson.publicKey,
+    });
+
+    /**
+     * @dev Build transaction
+     */
+    const transaction = new Transaction();
+    transaction.add(...ins);
+    transaction.recentBlockhash = (
+      await workspace.connection.getLatestBlockhash()
+    ).blockhash;
+    transaction.lastValidBlockHeight = (
+      await workspace.connection.getLatestBlockhash()
+    ).lastValidBlockHeight;
+    transaction.sign(otherPerson);
+
+    /**
+     * @dev Send transaction
+     */
+    try {
+      await workspace.provider.simulate(transaction);
+      throw new Error('should fail here');
+    } catch (e) {
+      expect(JSON.stringify(e).includes('InstructionError')).to.be.true;
+    }
+  });
+
   it('[cancel_challenge] should: outsider cannot cancel the challenge', async () => {
     /**
      * @dev Build instruction
@@ -281,4 +412,92 @@ describe('[manage_challenge]', async () => {
     // @ts-ignore
     expect(!!state.status.canceled).to.be.true;
   });
+
+  it('[expire_challenge] should: anyone can expire an abandoned challenge after the grace period', async () => {
+    const expiringChallengeId = Keypair.generate()
+      .publicKey.toBase58()
+      .slice(0, 10);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: expiringChallengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    /**
+     * @dev Mint to the player and join
+     */
+    let playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      otherPerson,
+      challengeInfo.rewardTokenMintAccount,
+      otherPerson.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId: expiringChallengeId,
+      amount: challengeInfo.minDeposit,
+      signer: otherPerson.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [otherPerson],
+    );
+
+    /**
+     * @dev Nobody submits a result until the grace period lapses
+     */
+    await sleepUntil(endTime.add(new BN(3)));
+
+    const ins = await workspace.instructionBuilder.expireChallenge({
+      challengeId: expiringChallengeId,
+      signer: otherPerson.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      otherPerson,
+    ]);
+
+    const state = await workspace.challengeState.getChallenge(
+      expiringChallengeId,
+    );
+    // @ts-ignore
+    expect(!!state.status.canceled).to.be.true;
+
+    /**
+     * @dev Player withdraws the deposit
+     */
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId: expiringChallengeId,
+        signer: otherPerson.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [otherPerson],
+    );
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      otherPerson,
+      challengeInfo.rewardTokenMintAccount,
+      otherPerson.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 100);
+  });
 });
@@ -1,5 +1,5 @@
 import * as anchor from '@project-serum/anchor';
-import { Program } from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
 import { Keypair, SendTransactionError, Transaction } from '@solana/web3.js';
 import { expect } from 'chai';
 
@@ -133,4 +133,46 @@ describe('[initialize_challenge_registry]', async () => {
       expect(e instanceof SendTransactionError).to.be.true;
     }
   });
+
+  it('[initialize_challenge_registry] should: deployer fail to set negative windows', async () => {
+    const windows = [
+      { expiryGracePeriod: new BN(-1) },
+      { disputeWindow: new BN(-1) },
+      { correctionWindow: new BN(-1) },
+    ];
+
+    for (const window of windows) {
+      try {
+        /**
+         * @dev Build instruction
+         */
+        const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+          allowedAdministrators: [],
+          signer: workspace.provider.publicKey,
+          ...window,
+        });
+
+        /**
+         * @dev Send transaction
+         */
+        const transaction = new Transaction();
+        transaction.add(...ins);
+
+        await workspace.provider.sendAndConfirm(transaction);
+
+        throw new Error('should be failed here');
+      } catch (e) {
+        expect(e instanceof SendTransactionError).to.be.true;
+      }
+    }
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallengeRegistry();
+
+    expect(state.expiryGracePeriod.toNumber()).eq(0);
+    expect(state.disputeWindow.toNumber()).eq(0);
+    expect(state.correctionWindow.toNumber()).eq(0);
+  });
 });
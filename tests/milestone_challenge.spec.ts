@@ -0,0 +1,343 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[milestone_challenge]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player1 = Keypair.generate();
+  const player2 = Keypair.generate();
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    joinDeadline: null,
+    milestones: null,
+    endTime: null,
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player1,
+      player2,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players
+     */
+    for (const player of [player1, player2]) {
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        tokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin and keep submitted results correctable for a while
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      correctionWindow: new BN(600),
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev Players join first, then check in at every milestone
+     */
+    const now = Math.floor(Date.now() / 1000);
+    challengeInfo.joinDeadline = new BN(now + 10);
+    challengeInfo.milestones = [new BN(now + 20), new BN(now + 30)];
+    challengeInfo.endTime = new BN(now + 40);
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[create_challenge] should: fail to create a challenge with unordered milestones', async () => {
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      milestones: [...challengeInfo.milestones].reverse(),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[create_challenge] should: owner can create a milestone challenge', async () => {
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.joinDeadline,
+      endTime: challengeInfo.endTime,
+      milestones: challengeInfo.milestones,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    /**
+     * @dev Players join the challenge
+     */
+    for (const player of [player1, player2]) {
+      const joinIns = await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.id,
+        amount: challengeInfo.minDeposit,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...joinIns),
+        [player],
+      );
+    }
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.milestones.length).eq(2);
+    expect(state.players.length).eq(2);
+  });
+
+  it('[record_check_in] should: fail to check in before the milestone opens', async () => {
+    const ins = await workspace.instructionBuilder.recordCheckIn({
+      challengeId: challengeInfo.id,
+      player: player1.publicKey,
+      milestoneIndex: 0,
+      signer: player1.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player1,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[record_check_in] should: other people fail to check in on behalf of a player', async () => {
+    await sleepUntil(challengeInfo.joinDeadline);
+
+    const ins = await workspace.instructionBuilder.recordCheckIn({
+      challengeId: challengeInfo.id,
+      player: player1.publicKey,
+      milestoneIndex: 0,
+      signer: player2.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player2,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[record_check_in] should: players can check in the open milestone', async () => {
+    for (const player of [player1, player2]) {
+      const ins = await workspace.instructionBuilder.recordCheckIn({
+        challengeId: challengeInfo.id,
+        player: player.publicKey,
+        milestoneIndex: 0,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+    }
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(
+      (state.players as any).every(
+        (player) => player.checkedInMilestones === 1,
+      ),
+    ).to.be.true;
+  });
+
+  it('[settle_milestone_challenge] should: fail to settle before the challenge ends', async () => {
+    const ins = await workspace.instructionBuilder.settleMilestoneChallenge({
+      challengeId: challengeInfo.id,
+      signer: player1.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player1,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[record_check_in] should: only player 1 checks in the second milestone', async () => {
+    await sleepUntil(challengeInfo.milestones[0]);
+
+    const ins = await workspace.instructionBuilder.recordCheckIn({
+      challengeId: challengeInfo.id,
+      player: player1.publicKey,
+      milestoneIndex: 1,
+      signer: player1.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player1,
+    ]);
+  });
+
+  it('[settle_milestone_challenge] should: anyone can settle the survivors as winners', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    const ins = await workspace.instructionBuilder.settleMilestoneChallenge({
+      challengeId: challengeInfo.id,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).pendingResult).to.be.true;
+
+    const players = state.players as any;
+    const survivor = players.find(
+      (player) => player.publicKey.toBase58() === player1.publicKey.toBase58(),
+    );
+    const eliminated = players.find(
+      (player) => player.publicKey.toBase58() === player2.publicKey.toBase58(),
+    );
+    expect(survivor.isWinner).to.be.true;
+    expect(survivor.isEliminated).to.be.false;
+    expect(eliminated.isWinner).to.be.false;
+    expect(eliminated.isEliminated).to.be.true;
+  });
+
+  it('[amend_winner_list] should: fail to amend the winner list settled by check-ins', async () => {
+    const ins = await workspace.instructionBuilder.amendWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player2.publicKey],
+      signer: administrator.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+});
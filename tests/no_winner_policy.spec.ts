@@ -0,0 +1,409 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[no_winner_policy]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  let playerTokenAccount: Account;
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    refundId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    treasuryId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    rolloverId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    targetId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    fallbackId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The rollover target stays open for a while
+     */
+    const targetEndTime = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+    const targetIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.targetId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: targetEndTime,
+      endTime: targetEndTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...targetIns),
+      [challengeOwnerKeypair],
+    );
+    const {
+      address: [targetPubkey],
+    } = await workspace.programFinder.getChallengeAccount(
+      challengeInfo.targetId,
+    );
+
+    /**
+     * @dev The challenges end shortly and the player joins all of them
+     */
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    for (const [challengeId, noWinnerPolicy] of [
+      [challengeInfo.refundId, { refundPlayers: {} }],
+      [challengeInfo.treasuryId, { sendToTreasury: {} }],
+      [challengeInfo.rolloverId, { rollOver: {} }],
+      [challengeInfo.fallbackId, { rollOver: {} }],
+    ]) {
+      const challengeIns = await workspace.instructionBuilder.createChallenge({
+        challengeId: challengeId as string,
+        rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+        minDeposit: challengeInfo.minDeposit,
+        joinDeadline: challengeInfo.endTime,
+        endTime: challengeInfo.endTime,
+        noWinnerPolicy: noWinnerPolicy as Record<string, any>,
+        noWinnerRolloverTarget: {
+          [challengeInfo.rolloverId]: targetPubkey,
+          // the fallback challenge rolls over into a challenge that never exists
+          [challengeInfo.fallbackId]: Keypair.generate().publicKey,
+        }[challengeId as string],
+        signer: challengeOwnerKeypair.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...challengeIns),
+        [challengeOwnerKeypair],
+      );
+
+      const joinIns = await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeId as string,
+        amount: challengeInfo.minDeposit,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...joinIns),
+        [player],
+      );
+    }
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[apply_no_winner_policy] should: fail to apply the policy before the result is final', async () => {
+    const ins = await workspace.instructionBuilder.applyNoWinnerPolicy({
+      challengeId: challengeInfo.treasuryId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[apply_no_winner_policy] should: anyone can send the prize pool to the treasury', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    /**
+     * @dev Owner submits an empty winner list for all challenges
+     */
+    for (const challengeId of [
+      challengeInfo.refundId,
+      challengeInfo.treasuryId,
+      challengeInfo.rolloverId,
+      challengeInfo.fallbackId,
+    ]) {
+      const submitIns = await workspace.instructionBuilder.submitWinnerList({
+        challengeId,
+        winnerList: [],
+        signer: challengeOwnerKeypair.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...submitIns),
+        [challengeOwnerKeypair],
+      );
+    }
+
+    const ins = await workspace.instructionBuilder.applyNoWinnerPolicy({
+      challengeId: challengeInfo.treasuryId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.treasuryId,
+    );
+    expect(!!(state.status as any).claimed).to.be.true;
+    expect(state.prizePool.eq(new BN(0))).to.be.true;
+
+    const treasury = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(treasury.balance.eq(challengeInfo.minDeposit)).to.be.true;
+  });
+
+  it('[apply_no_winner_policy] should: players can withdraw their deposits after the refund policy applies', async () => {
+    const ins = await workspace.instructionBuilder.applyNoWinnerPolicy({
+      challengeId: challengeInfo.refundId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.refundId,
+    );
+    expect(!!(state.status as any).canceled).to.be.true;
+
+    /**
+     * @dev Player withdraws the deposit
+     */
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId: challengeInfo.refundId,
+        signer: player.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [player],
+    );
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 70);
+  });
+
+  it('[apply_no_winner_policy] should: fail to refund or send to the treasury a rollover challenge', async () => {
+    const ins = await workspace.instructionBuilder.applyNoWinnerPolicy({
+      challengeId: challengeInfo.rolloverId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[apply_no_winner_rollover] should: anyone can roll the prize pool over into the designated challenge', async () => {
+    const ins = await workspace.instructionBuilder.applyNoWinnerRollover({
+      sourceChallengeId: challengeInfo.rolloverId,
+      targetChallengeId: challengeInfo.targetId,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    const source = await workspace.challengeState.getChallenge(
+      challengeInfo.rolloverId,
+    );
+    expect(!!(source.status as any).claimed).to.be.true;
+    expect(source.prizePool.eq(new BN(0))).to.be.true;
+
+    const target = await workspace.challengeState.getChallenge(
+      challengeInfo.targetId,
+    );
+    expect(target.prizePool.eq(challengeInfo.minDeposit)).to.be.true;
+    expect(target.rolloverPool.eq(challengeInfo.minDeposit)).to.be.true;
+    expect(target.donatePool.eq(new BN(0))).to.be.true;
+  });
+
+  it('[apply_no_winner_policy] should: players are refunded when the rollover target cannot receive the prize pool', async () => {
+    const ins = await workspace.instructionBuilder.applyNoWinnerPolicy({
+      challengeId: challengeInfo.fallbackId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.fallbackId,
+    );
+    expect(!!(state.status as any).canceled).to.be.true;
+
+    const withdrawIns =
+      await workspace.instructionBuilder.withdrawDepositedReward({
+        challengeId: challengeInfo.fallbackId,
+        signer: player.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...withdrawIns),
+      [player],
+    );
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 80);
+  });
+
+  it('[close_challenge] should: the rolled over funds go to the treasury once the canceled target is closed', async () => {
+    const cancelIns = await workspace.instructionBuilder.cancelChallenge({
+      challengeId: challengeInfo.targetId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...cancelIns),
+      [challengeOwnerKeypair],
+    );
+
+    const treasuryBefore = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+
+    const closeIns = await workspace.instructionBuilder.closeChallenge({
+      challengeId: challengeInfo.targetId,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...closeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const treasuryAfter = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(
+      treasuryAfter.balance
+        .sub(treasuryBefore.balance)
+        .eq(challengeInfo.minDeposit),
+    ).to.be.true;
+  });
+});
@@ -0,0 +1,309 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import { Keypair, Transaction, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[payout_model]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const donor = Keypair.generate();
+  const players = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    deposits: [10, 30, 60],
+  };
+
+  /**
+   * @dev Get the token balance of a keypair
+   */
+  const getBalance = async (keypair: Keypair) => {
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      keypair,
+      challengeInfo.rewardTokenMintAccount,
+      keypair.publicKey,
+    );
+    return Number(tokenAccount.amount);
+  };
+
+  /**
+   * @dev Create a challenge ending shortly that every player joins with their deposit
+   */
+  const createChallenge = async (
+    challengeId: string,
+    payoutModel: Record<string, any>,
+    deposits = challengeInfo.deposits,
+    minDeposit = challengeInfo.minDeposit,
+  ) => {
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      payoutModel,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    for (const [index, player] of players.entries()) {
+      const joinIns = await workspace.instructionBuilder.joinChallenge({
+        challengeId,
+        amount: new BN(LAMPORTS_PER_SOL * deposits[index]),
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...joinIns),
+        [player],
+      );
+    }
+
+    return endTime;
+  };
+
+  /**
+   * @dev The first two players win and claim, returns what each winner received
+   */
+  const claimAsWinners = async (challengeId: string) => {
+    const winners = [players[0], players[1]];
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId,
+      winnerList: winners.map((player) => player.publicKey),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const rewards = [];
+    for (const player of winners) {
+      const balanceBefore = await getBalance(player);
+      const ins = await workspace.instructionBuilder.claimReward({
+        challengeId,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      rewards.push((await getBalance(player)) - balanceBefore);
+    }
+
+    return rewards;
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      donor,
+      ...players,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players and donor
+     */
+    for (const keypair of [donor, ...players]) {
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        keypair,
+        challengeInfo.rewardTokenMintAccount,
+        keypair.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        tokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 300,
+      );
+    }
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[claim_reward] should: stake proportional winners claim their share in proportion to their deposits', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+    const endTime = await createChallenge(challengeId, {
+      stakeProportional: {},
+    });
+
+    const state = await workspace.challengeState.getChallenge(challengeId);
+    expect(!!(state.payoutModel as any).stakeProportional).to.be.true;
+    expect(state.prizePool.eq(new BN(LAMPORTS_PER_SOL * 100))).to.be.true;
+
+    await sleepUntil(endTime);
+
+    /**
+     * @dev Expect rewards, the winners deposited 40 tokens of the 100 tokens prize pool
+     */
+    const rewards = await claimAsWinners(challengeId);
+    expect(rewards).to.deep.eq([LAMPORTS_PER_SOL * 25, LAMPORTS_PER_SOL * 75]);
+  });
+
+  it('[claim_reward] should: stake back winners get their deposits back and split the forfeited deposits and donations', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+    const endTime = await createChallenge(challengeId, { stakeBack: {} });
+
+    const donateIns = await workspace.instructionBuilder.donateReward({
+      challengeId,
+      amount: new BN(LAMPORTS_PER_SOL * 20),
+      signer: donor.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...donateIns),
+      [donor],
+    );
+
+    const state = await workspace.challengeState.getChallenge(challengeId);
+    expect(!!(state.payoutModel as any).stakeBack).to.be.true;
+    expect(state.prizePool.eq(new BN(LAMPORTS_PER_SOL * 120))).to.be.true;
+    expect(state.donatePool.eq(new BN(LAMPORTS_PER_SOL * 20))).to.be.true;
+
+    await sleepUntil(endTime);
+
+    /**
+     * @dev Expect rewards, the winners split the 60 tokens forfeited by the loser and the 20 tokens donated
+     */
+    const rewards = await claimAsWinners(challengeId);
+    expect(rewards).to.deep.eq([
+      LAMPORTS_PER_SOL * (10 + 40),
+      LAMPORTS_PER_SOL * (30 + 40),
+    ]);
+  });
+
+  it('[claim_reward] should: stake proportional winners without any deposit split the prize pool equally', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+
+    /**
+     * @dev The winners join without depositing, the loser deposits 10 tokens
+     */
+    const endTime = await createChallenge(
+      challengeId,
+      { stakeProportional: {} },
+      [0, 0, 10],
+      new BN(0),
+    );
+
+    await sleepUntil(endTime);
+
+    /**
+     * @dev Expect rewards, each winner gets half of the 10 tokens prize pool
+     */
+    const rewards = await claimAsWinners(challengeId);
+    expect(rewards).to.deep.eq([LAMPORTS_PER_SOL * 5, LAMPORTS_PER_SOL * 5]);
+  });
+
+  it('[claim_reward] should: stake proportional winners without any deposit can still settle their claim', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+
+    /**
+     * @dev The first winner joins without depositing, so its share is zero
+     */
+    const endTime = await createChallenge(
+      challengeId,
+      { stakeProportional: {} },
+      [0, 30, 10],
+      new BN(0),
+    );
+
+    await sleepUntil(endTime);
+
+    /**
+     * @dev Expect rewards, the second winner takes the whole 40 tokens prize pool
+     */
+    const rewards = await claimAsWinners(challengeId);
+    expect(rewards).to.deep.eq([0, LAMPORTS_PER_SOL * 40]);
+
+    const state = await workspace.challengeState.getChallenge(challengeId);
+    expect(!!(state.status as any).claimed).to.be.true;
+  });
+});
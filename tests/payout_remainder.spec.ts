@@ -0,0 +1,372 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import { Keypair, Transaction, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[payout_remainder]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const players = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    lastClaimerId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    treasuryId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    topRankedId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    zeroShareId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+    deposits: [10, 10, 11],
+    // the smallest token units, so the prize pool is smaller than the winner count
+    zeroShareDeposits: [1, 1, 0],
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [challengeOwnerKeypair, administrator, ...players]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players
+     */
+    for (const player of players) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        playerTokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[create_challenge] should: anyone can create challenges with a payout remainder policy', async () => {
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const deposits = challengeInfo.deposits.map(
+      (deposit) => new BN(LAMPORTS_PER_SOL * deposit),
+    );
+    const zeroShareDeposits = challengeInfo.zeroShareDeposits.map(
+      (deposit) => new BN(deposit),
+    );
+
+    for (const [challengeId, payoutRemainderPolicy, amounts] of [
+      [challengeInfo.lastClaimerId, { lastClaimer: {} }, deposits],
+      [challengeInfo.treasuryId, { treasury: {} }, deposits],
+      [challengeInfo.topRankedId, { topRankedWinner: {} }, deposits],
+      [challengeInfo.zeroShareId, { lastClaimer: {} }, zeroShareDeposits],
+    ]) {
+      const ins = await workspace.instructionBuilder.createChallenge({
+        challengeId: challengeId as string,
+        rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+        minDeposit:
+          challengeId === challengeInfo.zeroShareId
+            ? new BN(0)
+            : challengeInfo.minDeposit,
+        joinDeadline: challengeInfo.endTime,
+        endTime: challengeInfo.endTime,
+        payoutRemainderPolicy: payoutRemainderPolicy as Record<string, any>,
+        signer: challengeOwnerKeypair.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+
+      /**
+       * @dev The prize pools of 31 tokens and 2 token units cannot be split equally between 3 winners
+       */
+      for (const [index, player] of players.entries()) {
+        const joinIns = await workspace.instructionBuilder.joinChallenge({
+          challengeId: challengeId as string,
+          amount: (amounts as BN[])[index],
+          signer: player.publicKey,
+        });
+        await workspace.provider.sendAndConfirm(
+          new Transaction().add(...joinIns),
+          [player],
+        );
+      }
+    }
+  });
+
+  it('[claim_reward] should: the last claimer gets the payout remainder', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.lastClaimerId,
+      winnerList: players.map((player) => player.publicKey),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const balancesBefore = [];
+    for (const player of players) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      balancesBefore.push(Number(playerTokenAccount.amount));
+
+      const ins = await workspace.instructionBuilder.claimReward({
+        challengeId: challengeInfo.lastClaimerId,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+    }
+
+    /**
+     * @dev Expect the payouts add up to the prize pool
+     */
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.lastClaimerId,
+    );
+    expect(state.payoutRemainder.eq(new BN(1))).to.be.true;
+
+    const share = Math.floor((LAMPORTS_PER_SOL * 31) / 3);
+    const expectedPayouts = [share, share, share + 1];
+    for (const [index, player] of players.entries()) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      expect(Number(playerTokenAccount.amount) - balancesBefore[index]).eq(
+        expectedPayouts[index],
+      );
+    }
+  });
+
+  it('[claim_reward] should: the payout remainder goes to the treasury', async () => {
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.treasuryId,
+      winnerList: players.map((player) => player.publicKey),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.treasuryId,
+      signer: players[0].publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      players[0],
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.treasuryId,
+    );
+    expect(state.payoutRemainder.eq(new BN(1))).to.be.true;
+
+    const treasury = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(treasury.balance.eq(new BN(1))).to.be.true;
+  });
+
+  it('[claim_reward] should: the top ranked winner gets the payout remainder whenever it claims', async () => {
+    /**
+     * @dev The third player is ranked first and claims before the others
+     */
+    const rankedPlayers = [players[2], players[0], players[1]];
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.topRankedId,
+      winnerList: rankedPlayers.map((player) => player.publicKey),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const payouts = [];
+    for (const player of rankedPlayers) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      const balanceBefore = Number(playerTokenAccount.amount);
+
+      const ins = await workspace.instructionBuilder.claimReward({
+        challengeId: challengeInfo.topRankedId,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+
+      const playerTokenAccountAfter = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      payouts.push(Number(playerTokenAccountAfter.amount) - balanceBefore);
+    }
+
+    /**
+     * @dev Expect the remainder is paid with the first claim only
+     */
+    const share = Math.floor((LAMPORTS_PER_SOL * 31) / 3);
+    expect(payouts).to.deep.eq([share + 1, share, share]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.topRankedId,
+    );
+    expect(state.payoutRemainder.eq(new BN(1))).to.be.true;
+    expect(!!(state.status as any).claimed).to.be.true;
+  });
+
+  it('[claim_reward] should: winners with a zero share settle their claim and the last claimer gets the whole prize pool', async () => {
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.zeroShareId,
+      winnerList: players.map((player) => player.publicKey),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const payouts = [];
+    for (const player of players) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      const balanceBefore = Number(playerTokenAccount.amount);
+
+      const ins = await workspace.instructionBuilder.claimReward({
+        challengeId: challengeInfo.zeroShareId,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+
+      const playerTokenAccountAfter = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      payouts.push(Number(playerTokenAccountAfter.amount) - balanceBefore);
+    }
+
+    /**
+     * @dev Expect the 2 token units are paid to the last claimer and the challenge is fully claimed
+     */
+    expect(payouts).to.deep.eq([0, 0, 2]);
+
+    const state = await workspace.challengeState.getChallenge(
+      challengeInfo.zeroShareId,
+    );
+    expect(state.payoutRemainder.eq(new BN(2))).to.be.true;
+    expect(!!(state.status as any).claimed).to.be.true;
+  });
+});
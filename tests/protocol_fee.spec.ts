@@ -0,0 +1,470 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[protocol_fee]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  let playerTokenAccount: Account;
+  let administratorTokenAccount: Account;
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Add admin and charge a 10% protocol fee
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      protocolFeeBps: 1000,
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The challenge ends shortly and the player joins
+     */
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.endTime,
+      endTime: challengeInfo.endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: challengeInfo.minDeposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+
+    /**
+     * @dev Create the administrator token account
+     */
+    administratorTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      administrator,
+      challengeInfo.rewardTokenMintAccount,
+      administrator.publicKey,
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[claim_reward] should: the protocol fee is taken from the prize pool once the result is final', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.collectedFee.eq(new BN(LAMPORTS_PER_SOL))).to.be.true;
+
+    const treasury = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(treasury.balance.eq(new BN(LAMPORTS_PER_SOL))).to.be.true;
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 99);
+  });
+
+  it('[withdraw_fees] should: outsider cannot withdraw the fees', async () => {
+    const ins = await workspace.instructionBuilder.withdrawFees({
+      mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+      amount: new BN(LAMPORTS_PER_SOL),
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[withdraw_fees] should: fail to withdraw more than the accrued fees', async () => {
+    const ins = await workspace.instructionBuilder.withdrawFees({
+      mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+      amount: new BN(LAMPORTS_PER_SOL * 2),
+      signer: administrator.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[withdraw_fees] should: administrator withdraws the accrued fees', async () => {
+    const ins = await workspace.instructionBuilder.withdrawFees({
+      mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+      amount: new BN(LAMPORTS_PER_SOL),
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const treasury = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(treasury.balance.eq(new BN(0))).to.be.true;
+
+    administratorTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      administrator,
+      challengeInfo.rewardTokenMintAccount,
+      administrator.publicKey,
+    );
+    expect(Number(administratorTokenAccount.amount)).eq(LAMPORTS_PER_SOL);
+  });
+
+  it('[claim_reward] should: the fees of a large prize pool do not overflow', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+    const deposit = new BN('100000000000000000');
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      BigInt(deposit.toString()),
+    );
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: deposit,
+      joinDeadline: endTime,
+      endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId,
+      amount: deposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+
+    await sleepUntil(endTime);
+
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId,
+      winnerList: [player.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect state, deposit * fee bps does not fit in u64
+     */
+    const state = await workspace.challengeState.getChallenge(challengeId);
+    expect(state.collectedFee.eq(deposit.divn(10))).to.be.true;
+
+    const treasury = await workspace.challengeState.getTreasury(
+      challengeInfo.rewardTokenMintAccount,
+    );
+    expect(treasury.balance.eq(deposit.divn(10))).to.be.true;
+  });
+
+  it('[set_mint_protocol_fee] should: outsider cannot override the protocol fee of a mint', async () => {
+    const ins = await workspace.instructionBuilder.setMintProtocolFee({
+      mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+      protocolFeeBps: 0,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[set_mint_protocol_fee] should: fail to override the protocol fee above the prize pool', async () => {
+    const ins = await workspace.instructionBuilder.setMintProtocolFee({
+      mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+      protocolFeeBps: 10001,
+      signer: administrator.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        administrator,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[set_mint_protocol_fee] should: administrator overrides the protocol fee and registry updates keep it', async () => {
+    const ins = await workspace.instructionBuilder.setMintProtocolFee({
+      mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+      protocolFeeBps: 2000,
+      signer: administrator.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      administrator,
+    ]);
+
+    const updateIns =
+      await workspace.instructionBuilder.updateChallengeRegistry({
+        allowedAdministrators: [administrator.publicKey],
+        protocolFeeBps: 1000,
+        signer: workspace.provider.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...updateIns),
+    );
+
+    /**
+     * @dev Expect the mint is still whitelisted with its override
+     */
+    const mintAccount = new PublicKey(challengeInfo.rewardTokenMintAccount);
+    const registry = await workspace.challengeState.getChallengeRegistry();
+    expect(
+      registry.allowedMintAccounts.some((mintInfo) =>
+        mintInfo.mintAccount.equals(mintAccount),
+      ),
+    ).to.be.true;
+    expect(
+      registry.mintProtocolFees.find((mintProtocolFee) =>
+        mintProtocolFee.mintAccount.equals(mintAccount),
+      ).protocolFeeBps,
+    ).eq(2000);
+  });
+
+  it('[claim_reward] should: the protocol fee override of the mint is charged instead of the registry fee', async () => {
+    const challengeId = Keypair.generate().publicKey.toBase58().slice(0, 10);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: endTime,
+      endTime,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    const joinIns = await workspace.instructionBuilder.joinChallenge({
+      challengeId,
+      amount: challengeInfo.minDeposit,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...joinIns),
+      [player],
+    );
+
+    await sleepUntil(endTime);
+
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId,
+      winnerList: [player.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect the 20% override of the mint, not the 10% registry fee
+     */
+    const state = await workspace.challengeState.getChallenge(challengeId);
+    expect(state.collectedFee.eq(new BN(LAMPORTS_PER_SOL * 2))).to.be.true;
+  });
+});
@@ -0,0 +1,269 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[ranked_payout]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const players = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+    payoutTable: [5000, 3000, 2000],
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [challengeOwnerKeypair, administrator, ...players]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to players
+     */
+    for (const player of players) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      await mintTo(
+        workspace.connection,
+        challengeOwnerKeypair,
+        challengeInfo.rewardTokenMintAccount,
+        playerTokenAccount.address,
+        challengeOwnerKeypair.publicKey,
+        LAMPORTS_PER_SOL * 100,
+      );
+    }
+
+    /**
+     * @dev Add admin
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[create_challenge] should: fail to create a ranked challenge with an invalid payout table', async () => {
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+
+    /**
+     * @dev The table must sum to 10000, give every rank a share and never pay a better rank less
+     */
+    for (const payoutTable of [
+      [5000, 3000],
+      [0, 10000],
+      [3000, 7000],
+    ]) {
+      const ins = await workspace.instructionBuilder.createChallenge({
+        challengeId: Keypair.generate().publicKey.toBase58().slice(0, 10),
+        rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+        minDeposit: challengeInfo.minDeposit,
+        joinDeadline: endTime,
+        endTime,
+        payoutModel: { ranked: {} },
+        payoutTable,
+        signer: challengeOwnerKeypair.publicKey,
+      });
+
+      try {
+        await workspace.provider.sendAndConfirm(
+          new Transaction().add(...ins),
+          [challengeOwnerKeypair],
+        );
+        throw new Error('should be failed');
+      } catch (e) {
+        expect(e instanceof SendTransactionError).to.be.true;
+      }
+    }
+  });
+
+  it('[create_challenge] should: anyone can create a ranked challenge', async () => {
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const ins = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.endTime,
+      endTime: challengeInfo.endTime,
+      payoutModel: { ranked: {} },
+      payoutTable: challengeInfo.payoutTable,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      challengeOwnerKeypair,
+    ]);
+
+    for (const player of players) {
+      const joinIns = await workspace.instructionBuilder.joinChallenge({
+        challengeId: challengeInfo.id,
+        amount: challengeInfo.minDeposit,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(
+        new Transaction().add(...joinIns),
+        [player],
+      );
+    }
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.payoutModel as any).ranked).to.be.true;
+    expect(state.payoutTable).to.deep.eq(challengeInfo.payoutTable);
+  });
+
+  it('[submit_winner_list] should: fail to rank the same winner twice', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    const ins = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [players[2], players[2], players[0]].map(
+        (player) => player.publicKey,
+      ),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        challengeOwnerKeypair,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[claim_reward] should: winners claim their share by rank', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    /**
+     * @dev The ranking is the order of the winner list
+     */
+    const ranking = [players[2], players[0], players[1]];
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: ranking.map((player) => player.publicKey),
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    for (const player of ranking) {
+      const ins = await workspace.instructionBuilder.claimReward({
+        challengeId: challengeInfo.id,
+        signer: player.publicKey,
+      });
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+    }
+
+    /**
+     * @dev Expect balance changes, the prize pool is 30 tokens
+     */
+    const expectedBalances = [90 + 15, 90 + 9, 90 + 6];
+    for (const [index, player] of ranking.entries()) {
+      const playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        workspace.connection,
+        player,
+        challengeInfo.rewardTokenMintAccount,
+        player.publicKey,
+      );
+      expect(Number(playerTokenAccount.amount)).eq(
+        LAMPORTS_PER_SOL * expectedBalances[index],
+      );
+    }
+
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(!!(state.status as any).claimed).to.be.true;
+  });
+});
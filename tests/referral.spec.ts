@@ -0,0 +1,314 @@
+import * as anchor from '@project-serum/anchor';
+import { BN, Program } from '@project-serum/anchor';
+import {
+  Keypair,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  SendTransactionError,
+} from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  Account,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+
+/**
+ * @dev Import deps
+ */
+import { Challenge } from '../client/challenge.idl';
+import { getWorkspace } from '../client';
+import { sleepUntil } from './helpers';
+
+/**
+ * @dev Initialize provider to get wallet provider, cluster node and program id.
+ * Theses params can be configured elsewhere that not related to anchor.
+ */
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.Challenge as Program<Challenge>;
+const walletProvider = provider.wallet as anchor.Wallet;
+
+/**
+ * @dev Execute tests
+ */
+describe('[referral]', async () => {
+  let workspace: Awaited<ReturnType<typeof getWorkspace>>;
+  const challengeOwnerKeypair = Keypair.generate();
+  const administrator = Keypair.generate();
+  const player = Keypair.generate();
+  const referrer = Keypair.generate();
+  let playerTokenAccount: Account;
+  let referrerTokenAccount: Account;
+
+  /**
+   * @dev Initialize challenge info
+   */
+  const challengeInfo = {
+    id: Keypair.generate().publicKey.toBase58().slice(0, 10),
+    minDeposit: new BN(LAMPORTS_PER_SOL * 10),
+    rewardTokenMintAccount: null,
+    endTime: null,
+  };
+
+  /**
+   * @dev Initialize workspace.
+   */
+  before(async () => {
+    workspace = await getWorkspace(
+      program.programId.toBase58(),
+      provider.connection.rpcEndpoint,
+      walletProvider,
+    );
+
+    /**
+     * @dev request airdrop for gas fee
+     */
+    for (const keypair of [
+      challengeOwnerKeypair,
+      administrator,
+      player,
+      referrer,
+    ]) {
+      await workspace.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          keypair.publicKey,
+          LAMPORTS_PER_SOL * 100,
+        ),
+      );
+    }
+
+    /**
+     * @dev Create a mint account
+     */
+    challengeInfo.rewardTokenMintAccount = await createMint(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeOwnerKeypair.publicKey,
+      challengeOwnerKeypair.publicKey,
+      9,
+    );
+
+    /**
+     * @dev Mint to player
+     */
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    await mintTo(
+      workspace.connection,
+      challengeOwnerKeypair,
+      challengeInfo.rewardTokenMintAccount,
+      playerTokenAccount.address,
+      challengeOwnerKeypair.publicKey,
+      LAMPORTS_PER_SOL * 100,
+    );
+
+    /**
+     * @dev Add admin and pay half of the creator fee to referrers
+     */
+    const ins = await workspace.instructionBuilder.updateChallengeRegistry({
+      allowedAdministrators: [administrator.publicKey],
+      maxCreatorFeeBps: 1000,
+      referralBps: 5000,
+      referralFeeSource: { creatorFee: {} },
+      signer: workspace.provider.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins));
+
+    /**
+     * @dev Admin create token vault first.
+     */
+    const createTokenVaultIns =
+      await workspace.instructionBuilder.createTokenVault({
+        mintTokenAddress: challengeInfo.rewardTokenMintAccount,
+        signer: administrator.publicKey,
+      });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...createTokenVaultIns),
+      [administrator],
+    );
+
+    /**
+     * @dev The challenge ends shortly
+     */
+    challengeInfo.endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+
+    const challengeIns = await workspace.instructionBuilder.createChallenge({
+      challengeId: challengeInfo.id,
+      rewardMintAddress: challengeInfo.rewardTokenMintAccount,
+      minDeposit: challengeInfo.minDeposit,
+      joinDeadline: challengeInfo.endTime,
+      endTime: challengeInfo.endTime,
+      creatorFeeBps: 1000,
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...challengeIns),
+      [challengeOwnerKeypair],
+    );
+
+    /**
+     * @dev The referrer token account receives the referral reward
+     */
+    referrerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      referrer,
+      challengeInfo.rewardTokenMintAccount,
+      referrer.publicKey,
+    );
+  });
+
+  /**
+   * @dev Declare test cases
+   */
+  it('[join_challenge] should: fail to join a challenge referred by the player itself', async () => {
+    const ins = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: challengeInfo.minDeposit,
+      referrer: player.publicKey,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[join_challenge] should: player joins a challenge with a referrer', async () => {
+    const ins = await workspace.instructionBuilder.joinChallenge({
+      challengeId: challengeInfo.id,
+      amount: challengeInfo.minDeposit,
+      referrer: referrer.publicKey,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.referrers.length).eq(1);
+    expect(state.referrers[0].publicKey.toBase58()).eq(
+      referrer.publicKey.toBase58(),
+    );
+    expect(state.players[0].referrerIndex).eq(0);
+  });
+
+  it('[claim_referral_reward] should: fail to claim the referral reward before the result is final', async () => {
+    const ins = await workspace.instructionBuilder.claimReferralReward({
+      challengeId: challengeInfo.id,
+      signer: referrer.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        referrer,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[claim_reward] should: the referral reward is taken from the creator fee once the result is final', async () => {
+    await sleepUntil(challengeInfo.endTime);
+
+    const submitIns = await workspace.instructionBuilder.submitWinnerList({
+      challengeId: challengeInfo.id,
+      winnerList: [player.publicKey],
+      signer: challengeOwnerKeypair.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(
+      new Transaction().add(...submitIns),
+      [challengeOwnerKeypair],
+    );
+
+    const ins = await workspace.instructionBuilder.claimReward({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      player,
+    ]);
+
+    /**
+     * @dev Expect state
+     */
+    const state = await workspace.challengeState.getChallenge(challengeInfo.id);
+    expect(state.creatorFee.eq(new BN(LAMPORTS_PER_SOL / 2))).to.be.true;
+    expect(
+      state.referrers[0].referralReward.eq(new BN(LAMPORTS_PER_SOL / 2)),
+    ).to.be.true;
+
+    playerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      player,
+      challengeInfo.rewardTokenMintAccount,
+      player.publicKey,
+    );
+    expect(Number(playerTokenAccount.amount)).eq(LAMPORTS_PER_SOL * 99);
+  });
+
+  it('[claim_referral_reward] should: outsider cannot claim the referral reward', async () => {
+    const ins = await workspace.instructionBuilder.claimReferralReward({
+      challengeId: challengeInfo.id,
+      signer: player.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+        player,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+
+  it('[claim_referral_reward] should: referrer claims the referral reward once', async () => {
+    const ins = await workspace.instructionBuilder.claimReferralReward({
+      challengeId: challengeInfo.id,
+      signer: referrer.publicKey,
+    });
+    await workspace.provider.sendAndConfirm(new Transaction().add(...ins), [
+      referrer,
+    ]);
+
+    referrerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      workspace.connection,
+      referrer,
+      challengeInfo.rewardTokenMintAccount,
+      referrer.publicKey,
+    );
+    expect(Number(referrerTokenAccount.amount)).eq(LAMPORTS_PER_SOL / 2);
+
+    /**
+     * @dev The referral reward cannot be claimed twice
+     */
+    const ins2 = await workspace.instructionBuilder.claimReferralReward({
+      challengeId: challengeInfo.id,
+      signer: referrer.publicKey,
+    });
+
+    try {
+      await workspace.provider.sendAndConfirm(new Transaction().add(...ins2), [
+        referrer,
+      ]);
+      throw new Error('should be failed');
+    } catch (e) {
+      expect(e instanceof SendTransactionError).to.be.true;
+    }
+  });
+});